@@ -0,0 +1,37 @@
+//! Criterion benchmarks for parsing and solving every registered day against its input. Days
+//! whose input is not cached are skipped.
+//!
+//! Run with `cargo bench`, or `cargo bench -- y2022_day_3` for a single day.
+
+#![allow(clippy::needless_return)]
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use advent_of_code::provider::InputProvider;
+use advent_of_code::DAYS;
+
+fn bench_days(c: &mut Criterion) {
+    let inputs = InputProvider::new(InputProvider::default_cache_dir());
+    for day in DAYS.iter() {
+        let text = match inputs.get(day.year, day.number) {
+            Ok(text) => text,
+            Err(err) if err.is_not_found() => {
+                eprintln!("Skipping {} day {}, its input is not cached.", day.year, day.number);
+                continue;
+            }
+            Err(err) => panic!("{} day {}: {}", day.year, day.number, err),
+        };
+        let solution = day.parse(&text).unwrap();
+
+        let mut group = c.benchmark_group(format!("y{}_day_{}", day.year, day.number));
+        group.bench_function("parse", |b| b.iter(|| day.parse(black_box(&text)).unwrap()));
+        group.bench_function("part_one", |b| b.iter(|| solution.part_one()));
+        group.bench_function("part_two", |b| b.iter(|| solution.part_two()));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
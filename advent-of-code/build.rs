@@ -0,0 +1,124 @@
+//! Generates a test for each part of every puzzle example found under `src/yYYYY/day_N/examples`.
+//!
+//! An example is a `<name>.txt` puzzle input with a `<name>.toml` sidecar holding the expected
+//! `part_one` and `part_two` answers. Either answer may be left out when it is not known.
+//!
+//! Also sets `AOC_BUILD_ID` to a SHA-256 of the crate's sources, `Cargo.lock` and this script, so
+//! that answers cached by one build of the solvers are not reused by another.
+
+#![allow(clippy::needless_return)]
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    println!("cargo:rustc-env=AOC_BUILD_ID={}", build_id(&manifest_dir));
+
+    let mut tests = String::new();
+    for (year, day, example) in find_examples(&manifest_dir.join("src")) {
+        let sidecar = example.with_extension("toml");
+        let text = fs::read_to_string(&sidecar)
+            .unwrap_or_else(|err| panic!("unable to read {}: {}", sidecar.display(), err));
+        let answers: toml::Table = toml::from_str(&text)
+            .unwrap_or_else(|err| panic!("unable to parse {}: {}", sidecar.display(), err));
+        let name = example.file_stem().unwrap().to_string_lossy().replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+
+        for (part, key) in [(1, "part_one"), (2, "part_two")] {
+            let Some(expected) = answers.get(key) else {
+                continue;
+            };
+            let expected = expected
+                .as_str()
+                .unwrap_or_else(|| panic!("{} in {} must be a string", key, sidecar.display()));
+            writeln!(
+                tests,
+                "#[test]\nfn y{}_day_{}_{}_part_{}() {{\n    check_example({}, {}, {:?}, {}, {:?});\n}}\n",
+                year, day, name, part, year, day, example.display().to_string(), part, expected
+            )
+            .unwrap();
+        }
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("examples.rs"), tests).unwrap();
+}
+
+/// Returns the SHA-256, as lowercase hex, of the path and contents of every Rust source file under
+/// `src`, of `Cargo.lock` when there is one, and of this script. Dependency upgrades and changes
+/// to how the tests are generated count as a new build as much as changes to the solvers do.
+fn build_id(manifest_dir: &Path) -> String {
+    let mut files: Vec<PathBuf> = vec![manifest_dir.join("Cargo.lock"), manifest_dir.join("build.rs")];
+    let mut dirs: Vec<PathBuf> = vec![manifest_dir.join("src")];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|extension| extension == "rs") {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for file in files {
+        let Ok(contents) = fs::read(&file) else {
+            continue;
+        };
+        // Each path and file is prefixed with its length, so that moving bytes from one file to the
+        // next cannot give the same hash.
+        let path = file.strip_prefix(manifest_dir).unwrap().to_string_lossy().into_owned();
+        for part in [path.as_bytes(), contents.as_slice()] {
+            hasher.update((part.len() as u64).to_le_bytes());
+            hasher.update(part);
+        }
+    }
+    return hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect();
+}
+
+/// Returns every example input, with its year and day, in a stable order.
+fn find_examples(src: &Path) -> Vec<(u16, u8, PathBuf)> {
+    let mut examples: Vec<(u16, u8, PathBuf)> = Vec::new();
+    for (year, year_dir) in numbered_dirs(src, "y") {
+        for (day, day_dir) in numbered_dirs(&year_dir, "day_") {
+            let Ok(entries) = fs::read_dir(day_dir.join("examples")) else {
+                continue;
+            };
+            for entry in entries {
+                let example = entry.unwrap().path();
+                if example.extension().is_some_and(|extension| extension == "txt") {
+                    examples.push((year as u16, day as u8, example));
+                }
+            }
+        }
+    }
+    examples.sort();
+    return examples;
+}
+
+/// Returns the directories named `<prefix><number>` directly inside `dir`, with their numbers.
+fn numbered_dirs(dir: &Path, prefix: &str) -> Vec<(u32, PathBuf)> {
+    let mut dirs: Vec<(u32, PathBuf)> = Vec::new();
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let number = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix(prefix))
+            .and_then(|number| number.parse::<u32>().ok());
+        if let Some(number) = number.filter(|_| path.is_dir()) {
+            dirs.push((number, path));
+        }
+    }
+    return dirs;
+}
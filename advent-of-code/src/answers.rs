@@ -0,0 +1,126 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+
+/// The recorded answer to one part of a day's puzzle, for a specific input file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Answer {
+    /// The year of the event.
+    pub year: u16,
+    /// The day of the event.
+    pub day: u8,
+    /// The part of the puzzle, either `1` or `2`.
+    pub part: u8,
+    /// The input file the answer was recorded for, relative to the inputs directory.
+    pub input: String,
+    /// The accepted answer.
+    pub answer: String,
+}
+
+/// The answers manifest, listing every answer known to be correct.
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    /// Returns the answers manifest loaded from a TOML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the answers manifest.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).map_err(|err| Error::io(path, err))?;
+        return Self::from_text(&text);
+    }
+
+    /// Returns the answers manifest parsed from the text of a TOML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The TOML text, with one `[[answer]]` table per recorded answer.
+    pub fn from_text(text: &str) -> Result<Self> {
+        return toml::from_str(text).map_err(|err| toml_error(text, &err));
+    }
+
+    /// Returns the recorded answer for a part of a day's puzzle and input file, if there is one.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - the year of the event.
+    /// * `day` - the day of the event.
+    /// * `part` - the part of the puzzle.
+    /// * `input` - the input file, relative to the inputs directory.
+    pub fn find(&self, year: u16, day: u8, part: u8, input: &str) -> Option<&Answer> {
+        return self
+            .answers
+            .iter()
+            .find(|answer| answer.year == year && answer.day == day && answer.part == part && answer.input == input);
+    }
+
+    /// Returns every recorded answer.
+    pub fn iter(&self) -> std::slice::Iter<'_, Answer> {
+        return self.answers.iter();
+    }
+}
+
+/// Returns a parse error pointing at the line of the TOML text that could not be read.
+pub(crate) fn toml_error(text: &str, err: &toml::de::Error) -> Error {
+    let Some(span) = err.span() else {
+        return Error::parse("", err.message());
+    };
+    let line = text[..span.start].matches('\n').count() + 1;
+    let offending = text.lines().nth(line - 1).unwrap_or("");
+    return Error::parse(offending, err.message()).at_line(line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+[[answer]]
+year = 2022
+day = 4
+part = 1
+input = "src/y2022/day_4/examples/example.txt"
+answer = "2"
+
+[[answer]]
+year = 2022
+day = 4
+part = 2
+input = "src/y2022/day_4/examples/example.txt"
+answer = "4"
+"#;
+
+    #[test]
+    fn test_find() {
+        let answers = Answers::from_text(MANIFEST).unwrap();
+        assert_eq!("4", answers.find(2022, 4, 2, "src/y2022/day_4/examples/example.txt").unwrap().answer);
+        assert!(answers.find(2022, 4, 2, "src/y2022/day_4/section_assignments.txt").is_none());
+        assert!(answers.find(2022, 3, 1, "src/y2022/day_4/examples/example.txt").is_none());
+        assert!(answers.find(2023, 4, 2, "src/y2022/day_4/examples/example.txt").is_none());
+    }
+
+    #[test]
+    fn test_missing_year() {
+        let error = Answers::from_text("[[answer]]\nday = 4\npart = 1\ninput = \"2022/4.txt\"\nanswer = \"2\"\n").unwrap_err();
+        assert!(error.to_string().contains("year"), "{}", error);
+    }
+
+    #[test]
+    fn test_empty_manifest() {
+        assert_eq!(0, Answers::from_text("").unwrap().iter().count());
+    }
+
+    #[test]
+    fn test_invalid_manifest() {
+        let error = Answers::from_text("[[answer]]\nday = \"four\"\n").unwrap_err();
+        assert!(matches!(error, Error::Parse { line: Some(2), .. }), "{}", error);
+    }
+}
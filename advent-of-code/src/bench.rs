@@ -0,0 +1,151 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::percentile::nearest_rank;
+use crate::solution::Day;
+
+/// The time taken by one phase of a solution, across every benchmark iteration.
+#[derive(Debug, Clone)]
+pub struct Timings {
+    /// Every sample, sorted from fastest to slowest.
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    /// Returns the timings for a set of samples, in any order.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - the time taken by each iteration. Must not be empty.
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "timings need at least one sample");
+        samples.sort();
+        return Self { samples };
+    }
+
+    /// Returns the fastest sample.
+    pub fn min(&self) -> Duration {
+        return self.samples[0];
+    }
+
+    /// Returns the median sample.
+    pub fn median(&self) -> Duration {
+        return self.percentile(50);
+    }
+
+    /// Returns the sample that the given percentage of samples are at least as fast as.
+    ///
+    /// # Arguments
+    ///
+    /// * `percent` - the percentile, from 0 to 100.
+    pub fn percentile(&self, percent: usize) -> Duration {
+        return nearest_rank(&self.samples, percent);
+    }
+}
+
+/// The timings for parsing and solving both parts of a day's puzzle.
+#[derive(Debug, Clone)]
+pub struct DayTimings {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Timings,
+    pub part_one: Timings,
+    pub part_two: Timings,
+}
+
+impl DayTimings {
+    /// Returns each phase's name alongside its timings, in the order they run.
+    pub fn phases(&self) -> [(&'static str, &Timings); 3] {
+        return [("parse", &self.parse), ("part one", &self.part_one), ("part two", &self.part_two)];
+    }
+}
+
+/// Returns the time taken to parse the input and answer each part, over a number of iterations.
+///
+/// # Arguments
+///
+/// * `day` - the registered day to benchmark.
+/// * `input` - the full text of the puzzle input.
+/// * `iterations` - the number of times to parse and solve the puzzle, at least 1.
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> Result<DayTimings> {
+    if iterations == 0 {
+        return Err(Error::Validation(String::from("expected at least one benchmark iteration")));
+    }
+    let mut parse: Vec<Duration> = Vec::with_capacity(iterations);
+    let mut part_one: Vec<Duration> = Vec::with_capacity(iterations);
+    let mut part_two: Vec<Duration> = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let solution = day.parse(black_box(input))?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(solution.part_one());
+        part_one.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(solution.part_two());
+        part_two.push(start.elapsed());
+    }
+
+    return Ok(DayTimings {
+        year: day.year,
+        day: day.number,
+        parse: Timings::new(parse),
+        part_one: Timings::new(part_one),
+        part_two: Timings::new(part_two),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::find_day;
+
+    fn millis(values: &[u64]) -> Timings {
+        return Timings::new(values.iter().map(|value| Duration::from_millis(*value)).collect());
+    }
+
+    #[test]
+    fn test_timings() {
+        let timings = millis(&[5, 1, 4, 2, 3, 10, 9, 8, 7, 6]);
+        assert_eq!(Duration::from_millis(1), timings.min());
+        assert_eq!(Duration::from_millis(5), timings.median());
+        assert_eq!(Duration::from_millis(10), timings.percentile(95));
+        assert_eq!(Duration::from_millis(9), timings.percentile(90));
+    }
+
+    #[test]
+    fn test_single_sample() {
+        let timings = millis(&[3]);
+        assert_eq!(Duration::from_millis(3), timings.min());
+        assert_eq!(Duration::from_millis(3), timings.median());
+        assert_eq!(Duration::from_millis(3), timings.percentile(95));
+        assert_eq!(Duration::from_millis(3), timings.percentile(0));
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let day = find_day(2022, 4).unwrap();
+        let timings = bench_day(day, "2-4,6-8\n2-8,3-7\n", 5).unwrap();
+        assert_eq!(4, timings.day);
+        for (_, phase) in timings.phases() {
+            assert!(phase.min() <= phase.median());
+            assert!(phase.median() <= phase.percentile(95));
+        }
+    }
+
+    #[test]
+    fn test_bench_day_invalid_input() {
+        let day = find_day(2022, 4).unwrap();
+        assert!(bench_day(day, "2-4\n", 5).is_err());
+    }
+
+    #[test]
+    fn test_bench_day_no_iterations() {
+        let day = find_day(2022, 4).unwrap();
+        assert!(matches!(bench_day(day, "2-4,6-8\n", 0), Err(Error::Validation(_))));
+    }
+}
@@ -0,0 +1,212 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::answers::toml_error;
+use crate::error::{Error, Result};
+
+/// Identifies the build of the solvers, changing whenever the crate's sources, `Cargo.lock` or
+/// the build script change.
+pub const BUILD_ID: &str = env!("AOC_BUILD_ID");
+
+/// Returns the SHA-256 of a puzzle input, as lowercase hex.
+///
+/// # Arguments
+///
+/// * `text` - the full text of the puzzle input.
+pub fn input_hash(text: &str) -> String {
+    return Sha256::digest(text.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect();
+}
+
+/// An answer computed by one build of the solvers for one puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    year: u16,
+    day: u8,
+    part: u8,
+    /// The SHA-256 of the puzzle input.
+    input: String,
+    build: String,
+    answer: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Entries {
+    #[serde(default, rename = "entry")]
+    entries: Vec<Entry>,
+}
+
+/// A store of computed answers, so that a part is only solved again when its input or the
+/// solvers change. Safe to share between the threads of a parallel run.
+#[derive(Debug)]
+pub struct AnswerCache {
+    build: String,
+    entries: Mutex<Vec<Entry>>,
+}
+
+impl Default for AnswerCache {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl AnswerCache {
+    /// Returns an empty cache for the current build.
+    pub fn new() -> Self {
+        return Self::for_build(BUILD_ID);
+    }
+
+    /// Returns an empty cache for a particular build, so tests can simulate a rebuild.
+    ///
+    /// # Arguments
+    ///
+    /// * `build` - the build id answers are stored and looked up under.
+    pub fn for_build(build: &str) -> Self {
+        return Self {
+            build: String::from(build),
+            entries: Mutex::new(Vec::new()),
+        };
+    }
+
+    /// Returns the cache loaded from a TOML file, or an empty cache if the file does not exist
+    /// yet. Answers from other builds are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the cache.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::new()),
+            Err(err) => return Err(Error::io(path, err)),
+        };
+        let Entries { mut entries } = toml::from_str(&text).map_err(|err| toml_error(&text, &err))?;
+        entries.retain(|entry| entry.build == BUILD_ID);
+        return Ok(Self {
+            build: String::from(BUILD_ID),
+            entries: Mutex::new(entries),
+        });
+    }
+
+    /// Writes the cache to a TOML file, creating its directory if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the cache.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let entries = Entries {
+            entries: self.entries.lock().unwrap().clone(),
+        };
+        let text = toml::to_string(&entries).expect("the answer cache is always valid TOML");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| Error::io(parent, err))?;
+        }
+        return fs::write(path, text).map_err(|err| Error::io(path, err));
+    }
+
+    /// Returns the cached answer to a part of a day's puzzle for an input, if this build has
+    /// computed it before.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - the year of the event.
+    /// * `day` - the day of the event.
+    /// * `part` - the part of the puzzle.
+    /// * `input` - the SHA-256 of the puzzle input, from `input_hash`.
+    pub fn get(&self, year: u16, day: u8, part: u8, input: &str) -> Option<String> {
+        return self
+            .entries
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|entry| {
+                (entry.year, entry.day, entry.part) == (year, day, part) && entry.input == input && entry.build == self.build
+            })
+            .map(|entry| entry.answer.clone());
+    }
+
+    /// Stores the answer to a part of a day's puzzle for an input, replacing any answer cached
+    /// for that part by an earlier input or build.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - the year of the event.
+    /// * `day` - the day of the event.
+    /// * `part` - the part of the puzzle.
+    /// * `input` - the SHA-256 of the puzzle input, from `input_hash`.
+    /// * `answer` - the computed answer.
+    pub fn insert(&self, year: u16, day: u8, part: u8, input: &str, answer: &str) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|entry| (entry.year, entry.day, entry.part) != (year, day, part));
+        entries.push(Entry {
+            year,
+            day,
+            part,
+            input: String::from(input),
+            build: self.build.clone(),
+            answer: String::from(answer),
+        });
+    }
+
+    /// Returns the number of cached answers.
+    pub fn len(&self) -> usize {
+        return self.entries.lock().unwrap().len();
+    }
+
+    /// Returns true if no answers are cached.
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", input_hash(""));
+        assert_ne!(input_hash("2-4,6-8\n"), input_hash("2-4,6-8\r\n"));
+    }
+
+    #[test]
+    fn test_build_id() {
+        assert_eq!(64, BUILD_ID.len());
+        assert!(BUILD_ID.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()), "{}", BUILD_ID);
+    }
+
+    #[test]
+    fn test_get_and_insert() {
+        let cache = AnswerCache::new();
+        let input = input_hash("2-4,6-8\n");
+        assert_eq!(None, cache.get(2022, 4, 1, &input));
+        cache.insert(2022, 4, 1, &input, "0");
+        assert_eq!(Some(String::from("0")), cache.get(2022, 4, 1, &input));
+        assert_eq!(None, cache.get(2022, 4, 2, &input));
+        assert_eq!(None, cache.get(2022, 4, 1, &input_hash("2-8,3-7\n")));
+
+        cache.insert(2022, 4, 1, &input_hash("2-8,3-7\n"), "1");
+        assert_eq!(1, cache.len());
+        assert_eq!(None, cache.get(2022, 4, 1, &input));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = env::temp_dir().join(format!("advent-of-code-cache-{}/answers.toml", std::process::id()));
+        let cache = AnswerCache::new();
+        cache.insert(2022, 1, 2, "abc", "45000");
+        cache.save(&path).unwrap();
+        assert_eq!(Some(String::from("45000")), AnswerCache::load(&path).unwrap().get(2022, 1, 2, "abc"));
+
+        let stale = AnswerCache::for_build("0000000000000000");
+        stale.insert(2022, 1, 2, "abc", "45000");
+        stale.save(&path).unwrap();
+        assert!(AnswerCache::load(&path).unwrap().is_empty());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,294 @@
+use std::path::PathBuf;
+
+use advent_of_code::runner::Format;
+use clap::{Args, Parser, Subcommand};
+
+/// Advent of Code in Rust.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// The configuration file to use instead of `aoc.toml`. Also set by `AOC_CONFIG`.
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+
+    /// The directory of cached puzzle inputs, overriding the configuration.
+    #[arg(long, global = true)]
+    pub inputs: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run the solution for a single day, or for every registered day of one or every event.
+    Run(RunArgs),
+    /// List every registered day.
+    List,
+    /// Check every registered day's answers against the answers manifest.
+    Verify(VerifyArgs),
+    /// Time parsing and each part of the puzzle, for one day or every registered day.
+    Bench(BenchArgs),
+    /// Create the module, input and example files for a new day and register it.
+    NewDay(NewDayArgs),
+    /// Submit the answer to one part of a day's puzzle, unless it is already known to be wrong.
+    Submit(SubmitArgs),
+    /// Re-run a day against its input and examples whenever one of them changes.
+    Watch(WatchArgs),
+    /// Print a Markdown table of every registered day's progress, or write it into the README.
+    Report(ReportArgs),
+    /// Show the ranking and per-day solve times from an exported private leaderboard.
+    Leaderboard(LeaderboardArgs),
+    /// Print a random puzzle input for a day, far larger than the real ones if need be.
+    Generate(GenerateArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    /// The year of the event. Defaults to the configured year, then to the most recent event with
+    /// a solved day, or to every event with `--all`.
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// The day to run.
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    pub day: Option<u8>,
+
+    /// Only run this part of the puzzle, instead of both.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// The puzzle input to use instead of the day's registered input, or `-` for stdin.
+    #[arg(short, long, requires = "day")]
+    pub input: Option<PathBuf>,
+
+    /// Run every registered day, of the event given by `--year` if there is one.
+    #[arg(short, long)]
+    pub all: bool,
+
+    /// The number of days to run at once with `--all`. Defaults to the number of CPUs.
+    #[arg(short, long, conflicts_with = "day", value_parser = clap::value_parser!(u32).range(1..))]
+    pub jobs: Option<u32>,
+
+    /// Solve every part with `--all`, instead of reusing answers cached by this build for
+    /// unchanged inputs.
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// How to print the answers: `plain` or `json`. Defaults to the configured format.
+    #[arg(short, long)]
+    pub format: Option<Format>,
+}
+
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    /// The answers manifest to check against, instead of the configured one.
+    #[arg(long)]
+    pub answers: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    /// The year of the event. Defaults to the configured year, then to the most recent event with
+    /// a solved day with `--day`, or to every event without it.
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// The day to benchmark, instead of every registered day.
+    #[arg(short, long)]
+    pub day: Option<u8>,
+
+    /// The number of times to parse and solve each puzzle. Defaults to the configured number.
+    #[arg(short = 'n', long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: Option<u32>,
+}
+
+#[derive(Debug, Args)]
+pub struct NewDayArgs {
+    /// The year of the event. Defaults to the configured year, then to the most recent event with
+    /// a solved day.
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// The day of the event.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// The title of the puzzle, used to name the day's `Solution` type. Defaults to "Day N".
+    #[arg(short, long)]
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct SubmitArgs {
+    /// The year of the event. Defaults to the configured year, then to the most recent event with
+    /// a solved day.
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// The day to submit an answer for.
+    #[arg(short, long)]
+    pub day: u8,
+
+    /// The part of the puzzle to submit an answer for.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+}
+
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    /// The year of the event. Defaults to the configured year, then to the most recent event with
+    /// a solved day.
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// The day to watch.
+    #[arg(short, long)]
+    pub day: u8,
+}
+
+#[derive(Debug, Args)]
+pub struct ReportArgs {
+    /// Rewrite the section of the README between the report markers instead of printing the table.
+    #[arg(short, long)]
+    pub write: bool,
+
+    /// The README to write to, instead of the one at the root of the repository.
+    #[arg(long, requires = "write")]
+    pub readme: Option<PathBuf>,
+
+    /// Include each day's runtime in the README. The printed table always has them, while the
+    /// README leaves them out by default so that it only changes when the progress does.
+    #[arg(long, requires = "write")]
+    pub runtimes: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct LeaderboardArgs {
+    /// The JSON exported from the private leaderboard's API link.
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Args)]
+pub struct GenerateArgs {
+    /// The year of the event. Defaults to the configured year, then to the most recent event with
+    /// a solved day.
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// The day to generate an input for.
+    #[arg(short, long)]
+    pub day: u8,
+
+    /// How many elves, rounds, groups of three rucksacks or pairs to generate.
+    #[arg(short, long, default_value_t = 1000)]
+    pub size: usize,
+
+    /// The seed for the random number generator. Defaults to one based on the current time, which
+    /// is printed so the input can be generated again.
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// The file to write the input to, instead of stdout.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_run_day_part_input() {
+        let cli = Cli::parse_from(["advent-of-code", "run", "--day", "3", "--part", "2", "--input", "input.txt"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert_eq!(Some(3), args.day);
+        assert_eq!(Some(2), args.part);
+        assert_eq!(Some(PathBuf::from("input.txt")), args.input);
+        assert_eq!(false, args.all);
+        assert_eq!(None, args.format);
+    }
+
+    #[test]
+    fn test_run_format() {
+        let cli = Cli::parse_from(["advent-of-code", "run", "--all", "--format", "json"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert_eq!(Some(Format::Json), args.format);
+        assert!(Cli::try_parse_from(["advent-of-code", "run", "--all", "--format", "yaml"]).is_err());
+    }
+
+    #[test]
+    fn test_global_overrides() {
+        let cli = Cli::parse_from(["advent-of-code", "list", "--inputs", "/elsewhere", "--config", "team.toml"]);
+        assert_eq!(Some(PathBuf::from("/elsewhere")), cli.inputs);
+        assert_eq!(Some(PathBuf::from("team.toml")), cli.config);
+    }
+
+    #[test]
+    fn test_generate_defaults() {
+        let cli = Cli::parse_from(["advent-of-code", "generate", "--day", "3"]);
+        let Command::Generate(args) = cli.command else {
+            panic!("expected the generate command");
+        };
+        assert_eq!(3, args.day);
+        assert_eq!(1000, args.size);
+        assert_eq!(None, args.seed);
+        assert_eq!(None, args.output);
+    }
+
+    #[test]
+    fn test_run_rejects_unknown_part() {
+        assert!(Cli::try_parse_from(["advent-of-code", "run", "--day", "3", "--part", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_submit_requires_day_and_part() {
+        let cli = Cli::parse_from(["advent-of-code", "submit", "--day", "4", "--part", "2"]);
+        let Command::Submit(args) = cli.command else {
+            panic!("expected the submit command");
+        };
+        assert_eq!(4, args.day);
+        assert_eq!(2, args.part);
+        assert!(Cli::try_parse_from(["advent-of-code", "submit", "--day", "4"]).is_err());
+    }
+
+    #[test]
+    fn test_year() {
+        let cli = Cli::parse_from(["advent-of-code", "run", "--year", "2022", "--day", "1"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert_eq!(Some(2022), args.year);
+        let cli = Cli::parse_from(["advent-of-code", "new-day", "1", "--year", "2023"]);
+        let Command::NewDay(args) = cli.command else {
+            panic!("expected the new-day command");
+        };
+        assert_eq!(Some(2023), args.year);
+    }
+
+    #[test]
+    fn test_run_jobs() {
+        let cli = Cli::parse_from(["advent-of-code", "run", "--all", "--jobs", "4"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert_eq!(Some(4), args.jobs);
+        assert!(Cli::try_parse_from(["advent-of-code", "run", "--all", "--jobs", "0"]).is_err());
+        assert!(Cli::try_parse_from(["advent-of-code", "run", "--day", "1", "--jobs", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_run_all_conflicts_with_day() {
+        assert!(Cli::try_parse_from(["advent-of-code", "run", "--all", "--day", "3"]).is_err());
+    }
+}
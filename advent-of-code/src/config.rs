@@ -0,0 +1,229 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::answers::toml_error;
+use crate::error::{Error, Result};
+use crate::provider::{InputProvider, SESSION_VAR};
+use crate::runner::Format;
+
+/// The environment variable naming the configuration file to use instead of the crate's `aoc.toml`.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// The project configuration, read from `aoc.toml` and then overridden by `AOC_*` environment
+/// variables. Every setting is optional in the file.
+///
+/// ```toml
+/// inputs = "../private-inputs"
+/// year = 2022
+/// format = "json"
+/// answers = "answers.toml"
+/// submissions = "../private-inputs/submissions.toml"
+/// cache = "/tmp/aoc/answer-cache.toml"
+/// iterations = 20
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The directory holding a folder of puzzle inputs for each year. Overridden by `AOC_INPUTS`.
+    pub inputs: PathBuf,
+    /// The event used when no year is given, instead of the most recent one with a solved day.
+    /// Overridden by `AOC_YEAR`.
+    pub year: Option<u16>,
+    /// How the runner prints its results. Overridden by `AOC_FORMAT`.
+    pub format: Format,
+    /// The answers manifest checked by `verify`. Overridden by `AOC_ANSWERS`.
+    pub answers: PathBuf,
+    /// The log of answers sent by `submit`, kept with the personal puzzle inputs by default since
+    /// both belong to one account. Overridden by `AOC_SUBMISSIONS`.
+    pub submissions: PathBuf,
+    /// The answers computed by `run --all`, reused while the input and the build are unchanged.
+    /// Overridden by `AOC_CACHE`.
+    pub cache: PathBuf,
+    /// The number of times `bench` parses and solves each puzzle. Overridden by `AOC_ITERATIONS`.
+    pub iterations: u32,
+    /// The session cookie used to download inputs and submit answers. Best left out of the file
+    /// and set with `AOC_SESSION` instead.
+    pub session: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        return Self {
+            inputs: InputProvider::default_cache_dir(),
+            year: None,
+            format: Format::Plain,
+            answers: root.join("answers.toml"),
+            submissions: InputProvider::default_cache_dir().join("submissions.toml"),
+            cache: root.join("target/answer-cache.toml"),
+            iterations: 100,
+            session: None,
+        };
+    }
+}
+
+impl Config {
+    /// Returns where the configuration is read from when `AOC_CONFIG` is not set.
+    pub fn default_path() -> PathBuf {
+        return Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.toml");
+    }
+
+    /// Returns the configuration loaded from a TOML file, or the defaults if the file does not
+    /// exist. Relative paths in the file are relative to the file's directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the configuration file.
+    pub fn load(path: &Path) -> Result<Self> {
+        return match fs::read_to_string(path) {
+            Ok(text) => Self::from_text(&text, path.parent().unwrap_or(Path::new("."))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::io(path, err)),
+        };
+    }
+
+    /// Returns the configuration parsed from the text of a TOML file, once it has been validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The TOML text.
+    /// * `dir` - the directory relative paths are resolved against.
+    pub fn from_text(text: &str, dir: &Path) -> Result<Self> {
+        let mut config: Self = toml::from_str(text).map_err(|err| toml_error(text, &err))?;
+        config.inputs = dir.join(&config.inputs);
+        config.answers = dir.join(&config.answers);
+        config.submissions = dir.join(&config.submissions);
+        config.cache = dir.join(&config.cache);
+        return config.validate();
+    }
+
+    /// Returns the configuration with any settings overridden by `AOC_*` environment variables,
+    /// once it has been validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` - looks up an environment variable, such as `|name| std::env::var(name).ok()`.
+    pub fn with_env(mut self, var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        if let Some(inputs) = var("AOC_INPUTS") {
+            self.inputs = PathBuf::from(inputs);
+        }
+        if let Some(year) = var("AOC_YEAR") {
+            self.year = Some(year.parse().map_err(|_| Error::parse(&year, "expected AOC_YEAR to be a year"))?);
+        }
+        if let Some(format) = var("AOC_FORMAT") {
+            self.format = format.parse().map_err(|message: String| Error::parse(&format, &message))?;
+        }
+        if let Some(answers) = var("AOC_ANSWERS") {
+            self.answers = PathBuf::from(answers);
+        }
+        if let Some(submissions) = var("AOC_SUBMISSIONS") {
+            self.submissions = PathBuf::from(submissions);
+        }
+        if let Some(cache) = var("AOC_CACHE") {
+            self.cache = PathBuf::from(cache);
+        }
+        if let Some(iterations) = var("AOC_ITERATIONS") {
+            self.iterations = iterations
+                .parse()
+                .map_err(|_| Error::parse(&iterations, "expected AOC_ITERATIONS to be a number"))?;
+        }
+        if let Some(session) = var(SESSION_VAR).filter(|session| !session.trim().is_empty()) {
+            self.session = Some(session);
+        }
+        return self.validate();
+    }
+
+    /// Returns the configuration if every setting is usable, whichever source it came from.
+    fn validate(self) -> Result<Self> {
+        if self.iterations == 0 {
+            return Err(Error::Validation(String::from("expected iterations to be at least 1")));
+        }
+        return Ok(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_defaults() {
+        let config = Config::from_text("", Path::new("/project")).unwrap();
+        assert_eq!(Path::new("/project").join(Config::default().inputs), config.inputs);
+        assert_eq!(None, config.year);
+        assert_eq!(Format::Plain, config.format);
+        assert_eq!(100, config.iterations);
+    }
+
+    #[test]
+    fn test_from_text() {
+        let text = "inputs = \"../private\"\nyear = 2022\nformat = \"json\"\nanswers = \"answers.toml\"\nsubmissions = \"../private/submissions.toml\"\ncache = \"/tmp/cache.toml\"\niterations = 5\n";
+        let config = Config::from_text(text, Path::new("/project")).unwrap();
+        assert_eq!(PathBuf::from("/project/../private"), config.inputs);
+        assert_eq!(Some(2022), config.year);
+        assert_eq!(Format::Json, config.format);
+        assert_eq!(PathBuf::from("/project/answers.toml"), config.answers);
+        assert_eq!(PathBuf::from("/project/../private/submissions.toml"), config.submissions);
+        assert_eq!(PathBuf::from("/tmp/cache.toml"), config.cache);
+        assert_eq!(5, config.iterations);
+    }
+
+    #[test]
+    fn test_unknown_setting() {
+        let error = Config::from_text("year = 2022\ninput = \"inputs\"\n", Path::new(".")).unwrap_err();
+        assert!(matches!(error, Error::Parse { line: Some(2), .. }), "{}", error);
+    }
+
+    #[test]
+    fn test_zero_iterations() {
+        let error = Config::from_text("iterations = 0\n", Path::new(".")).unwrap_err();
+        assert!(matches!(error, Error::Validation(_)), "{}", error);
+    }
+
+    #[test]
+    fn test_committed_file_is_defaults() {
+        assert_eq!(Config::default(), Config::load(&Config::default_path()).unwrap());
+    }
+
+    #[test]
+    fn test_missing_file() {
+        assert_eq!(Config::default(), Config::load(Path::new("does-not-exist/aoc.toml")).unwrap());
+    }
+
+    #[test]
+    fn test_with_env() {
+        let vars = HashMap::from([
+            ("AOC_INPUTS", "/elsewhere"),
+            ("AOC_YEAR", "2023"),
+            ("AOC_FORMAT", "json"),
+            ("AOC_SUBMISSIONS", "/elsewhere/submissions.toml"),
+            ("AOC_CACHE", "/elsewhere/cache.toml"),
+            ("AOC_ITERATIONS", "7"),
+            ("AOC_SESSION", "secret"),
+        ]);
+        let config = Config::from_text("year = 2022\niterations = 5\n", Path::new("."))
+            .unwrap()
+            .with_env(|name| vars.get(name).map(|value| String::from(*value)))
+            .unwrap();
+        assert_eq!(PathBuf::from("/elsewhere"), config.inputs);
+        assert_eq!(Some(2023), config.year);
+        assert_eq!(Format::Json, config.format);
+        assert_eq!(PathBuf::from("/elsewhere/submissions.toml"), config.submissions);
+        assert_eq!(PathBuf::from("/elsewhere/cache.toml"), config.cache);
+        assert_eq!(7, config.iterations);
+        assert_eq!(Some(String::from("secret")), config.session);
+    }
+
+    #[test]
+    fn test_with_env_rejects_bad_values() {
+        assert!(Config::default().with_env(|name| (name == "AOC_YEAR").then(|| String::from("soon"))).is_err());
+        assert!(Config::default().with_env(|name| (name == "AOC_ITERATIONS").then(|| String::from("many"))).is_err());
+        let error = Config::default().with_env(|name| (name == "AOC_ITERATIONS").then(|| String::from("0"))).unwrap_err();
+        assert!(matches!(error, Error::Validation(_)), "{}", error);
+        assert!(Config::default().with_env(|name| (name == "AOC_FORMAT").then(|| String::from("yaml"))).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Everything that can go wrong while loading or solving a puzzle input.
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read or written.
+    Io {
+        /// The file being accessed, if there was one.
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+    /// A line of the puzzle input could not be parsed.
+    Parse {
+        /// The 1-based line number, if the text came from a multi-line input.
+        line: Option<usize>,
+        /// The offending text.
+        text: String,
+        /// What was expected instead.
+        message: String,
+    },
+    /// The puzzle input was well formed but does not describe a valid puzzle.
+    Validation(String),
+    /// A request to the Advent of Code website failed.
+    Http {
+        /// The URL that was requested.
+        url: String,
+        message: String,
+    },
+}
+
+/// A `Result` with the crate's `Error` type.
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Returns an I/O error for the file at `path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - the file being accessed.
+    /// * `source` - the underlying I/O error.
+    pub fn io(path: &Path, source: io::Error) -> Self {
+        return Self::Io {
+            path: Some(path.to_path_buf()),
+            source,
+        };
+    }
+
+    /// Returns a parse error for `text`, without a line number yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - the offending text.
+    /// * `message` - what was expected instead.
+    pub fn parse(text: &str, message: &str) -> Self {
+        return Self::Parse {
+            line: None,
+            text: String::from(text),
+            message: String::from(message),
+        };
+    }
+
+    /// Returns the error with the line number it occurred on, for parse errors that do not have one.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - the 1-based line number.
+    pub fn at_line(self, number: usize) -> Self {
+        return match self {
+            Self::Parse { line: None, text, message } => Self::Parse {
+                line: Some(number),
+                text,
+                message,
+            },
+            error => error,
+        };
+    }
+
+    /// Returns true if this is an I/O error for a file that does not exist, such as a puzzle
+    /// input that is not cached and cannot be downloaded.
+    pub fn is_not_found(&self) -> bool {
+        return matches!(self, Self::Io { source, .. } if source.kind() == io::ErrorKind::NotFound);
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Self::Io { path: Some(path), source } => write!(f, "unable to access {}: {}", path.display(), source),
+            Self::Io { path: None, source } => write!(f, "unable to read input: {}", source),
+            Self::Parse { line: Some(line), text, message } => write!(f, "line {}: {} (found {:?})", line, message, text),
+            Self::Parse { line: None, text, message } => write!(f, "{} (found {:?})", message, text),
+            Self::Validation(message) => write!(f, "invalid puzzle input: {}", message),
+            Self::Http { url, message } => write!(f, "request to {} failed: {}", url, message),
+        };
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        };
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(source: io::Error) -> Self {
+        return Self::Io { path: None, source };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_at_line() {
+        let error = Error::parse("abc", "expected a number").at_line(3);
+        assert_eq!("line 3: expected a number (found \"abc\")", error.to_string());
+    }
+
+    #[test]
+    fn test_at_line_keeps_existing_line() {
+        let error = Error::parse("abc", "expected a number").at_line(3).at_line(7);
+        assert_eq!("line 3: expected a number (found \"abc\")", error.to_string());
+    }
+
+    #[test]
+    fn test_io_error_names_path() {
+        let source = io::Error::new(io::ErrorKind::NotFound, "not found");
+        let error = Error::io(Path::new("input.txt"), source);
+        assert_eq!("unable to access input.txt: not found", error.to_string());
+    }
+}
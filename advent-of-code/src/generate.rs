@@ -0,0 +1,254 @@
+use crate::error::{Error, Result};
+
+/// The letters that can be rucksack items, in priority order.
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A small seeded pseudo-random number generator (SplitMix64), so that a seed always produces the
+/// same input on every platform and release.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Returns a generator that produces the sequence for `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - any number, the same seed always gives the same sequence.
+    pub fn new(seed: u64) -> Self {
+        return Self { state: seed };
+    }
+
+    /// Returns the next number in the sequence.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        return z ^ (z >> 31);
+    }
+
+    /// Returns a number between `low` and `high`, both inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - the smallest number that can be returned.
+    /// * `high` - the largest number that can be returned, at least `low`.
+    pub fn between(&mut self, low: u64, high: u64) -> u64 {
+        return low + self.next_u64() % (high - low + 1);
+    }
+
+    /// Shuffles a slice in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - the slice to shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.between(0, i as u64) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// Returns a randomly chosen item of a non-empty slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - the slice to choose from.
+    pub fn choose<T: Copy>(&mut self, items: &[T]) -> T {
+        return items[self.between(0, items.len() as u64 - 1) as usize];
+    }
+}
+
+/// Returns a random puzzle input for a day that has a generator.
+///
+/// # Arguments
+///
+/// * `year` - the year of the event.
+/// * `day` - the day of the event.
+/// * `size` - how many elves, rounds, groups of three rucksacks or pairs to generate.
+/// * `seed` - the seed for the random number generator.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::generate::generate;
+///
+/// let text = generate(2022, 4, 1000, 42)?;
+/// assert_eq!(1000, text.lines().count());
+/// assert_eq!(text, generate(2022, 4, 1000, 42)?);
+/// # Ok::<(), advent_of_code::Error>(())
+/// ```
+pub fn generate(year: u16, day: u8, size: usize, seed: u64) -> Result<String> {
+    let mut rng = Rng::new(seed);
+    return match (year, day) {
+        (2022, 1) => Ok(calories(&mut rng, size)),
+        (2022, 2) => Ok(rounds(&mut rng, size)),
+        (2022, 3) => Ok(rucksacks(&mut rng, size)),
+        (2022, 4) => Ok(pairs(&mut rng, size)),
+        _ => Err(Error::Validation(format!("there is no input generator for {} day {}", year, day))),
+    };
+}
+
+/// Returns the calories carried by `elves` elves, with a blank line between elves.
+///
+/// # Arguments
+///
+/// * `rng` - the random number generator.
+/// * `elves` - the number of elves.
+pub fn calories(rng: &mut Rng, elves: usize) -> String {
+    let mut groups: Vec<String> = Vec::with_capacity(elves);
+    for _ in 0..elves {
+        let items: Vec<String> = (0..rng.between(1, 15)).map(|_| rng.between(1000, 60000).to_string()).collect();
+        groups.push(items.join("\n"));
+    }
+    return groups.join("\n\n") + "\n";
+}
+
+/// Returns `count` rounds of rock paper scissors, such as `A Y`.
+///
+/// # Arguments
+///
+/// * `rng` - the random number generator.
+/// * `count` - the number of rounds.
+pub fn rounds(rng: &mut Rng, count: usize) -> String {
+    let mut text = String::with_capacity(count * 4);
+    for _ in 0..count {
+        text.push(rng.choose(&['A', 'B', 'C']));
+        text.push(' ');
+        text.push(rng.choose(&['X', 'Y', 'Z']));
+        text.push('\n');
+    }
+    return text;
+}
+
+/// Returns `groups` groups of three rucksacks. Both compartments of every rucksack share exactly
+/// one item type, and the three rucksacks of every group share exactly one badge.
+///
+/// # Arguments
+///
+/// * `rng` - the random number generator.
+/// * `groups` - the number of groups of three rucksacks.
+pub fn rucksacks(rng: &mut Rng, groups: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..groups {
+        let mut letters = ITEMS.to_vec();
+        rng.shuffle(&mut letters);
+        // The badge and each rucksack's shared item are kept apart from the filler items, and
+        // every rucksack has its own filler for each compartment, so nothing else can repeat.
+        let badge = letters[0];
+        let mut pools = letters[4..].chunks(8);
+        for elf in 0..3 {
+            let shared = letters[1 + elf];
+            let length = rng.between(2, 16) as usize;
+            let mut halves: [Vec<u8>; 2] = [pools.next().unwrap(), pools.next().unwrap()]
+                .map(|pool| (1..length).map(|_| rng.choose(pool)).collect());
+            halves[0].push(shared);
+            halves[1].push(shared);
+            let half = rng.between(0, 1) as usize;
+            halves[half][0] = badge;
+            for half in halves.iter_mut() {
+                rng.shuffle(half);
+                text.extend(half.iter().map(|item| *item as char));
+            }
+            text.push('\n');
+        }
+    }
+    return text;
+}
+
+/// Returns `count` pairs of section assignments, such as `2-4,6-8`.
+///
+/// # Arguments
+///
+/// * `rng` - the random number generator.
+/// * `count` - the number of pairs.
+pub fn pairs(rng: &mut Rng, count: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..count {
+        let [a, b, c, d] = [(); 4].map(|_| rng.between(1, 99));
+        text.push_str(&format!("{}-{},{}-{}\n", a.min(b), a.max(b), c.min(d), c.max(d)));
+    }
+    return text;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_day;
+    use std::collections::HashSet;
+
+    fn item_types(items: &str) -> HashSet<char> {
+        return items.chars().collect();
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        for day in 1..=4 {
+            assert_eq!(generate(2022, day, 50, 7).unwrap(), generate(2022, day, 50, 7).unwrap());
+            assert_ne!(generate(2022, day, 50, 7).unwrap(), generate(2022, day, 50, 8).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_parse() {
+        for day in 1..=4 {
+            let text = generate(2022, day, 300, day as u64).unwrap();
+            let solution = find_day(2022, day).unwrap().parse(&text).unwrap();
+            assert!(!solution.part_one().is_empty());
+            assert!(!solution.part_two().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_unknown_day() {
+        assert!(matches!(generate(2022, 25, 10, 0), Err(Error::Validation(_))));
+    }
+
+    #[test]
+    fn test_sizes() {
+        let mut rng = Rng::new(1);
+        assert_eq!(40, calories(&mut rng, 40).split("\n\n").count());
+        assert_eq!(40, rounds(&mut rng, 40).lines().count());
+        assert_eq!(120, rucksacks(&mut rng, 40).lines().count());
+        assert_eq!(40, pairs(&mut rng, 40).lines().count());
+    }
+
+    #[test]
+    fn test_rucksacks_share_one_item_and_one_badge() {
+        let text = rucksacks(&mut Rng::new(3), 200);
+        let lines: Vec<&str> = text.lines().collect();
+        for line in lines.iter() {
+            assert_eq!(0, line.len() % 2, "{}", line);
+            let (left, right) = line.split_at(line.len() / 2);
+            assert_eq!(1, item_types(left).intersection(&item_types(right)).count(), "{}", line);
+        }
+        for group in lines.chunks(3) {
+            let common: HashSet<char> = item_types(group[0])
+                .intersection(&item_types(group[1]))
+                .copied()
+                .collect::<HashSet<char>>()
+                .intersection(&item_types(group[2]))
+                .copied()
+                .collect();
+            assert_eq!(1, common.len(), "{:?}", group);
+        }
+    }
+
+    #[test]
+    fn test_pairs_are_ordered() {
+        for line in pairs(&mut Rng::new(5), 200).lines() {
+            let bounds: Vec<u64> = line.split([',', '-']).map(|bound| bound.parse().unwrap()).collect();
+            assert!(bounds[0] <= bounds[1] && bounds[2] <= bounds[3], "{}", line);
+            assert!(bounds.iter().all(|bound| (1..=99).contains(bound)), "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_between() {
+        let mut rng = Rng::new(0);
+        let rolls: HashSet<u64> = (0..1000).map(|_| rng.between(3, 6)).collect();
+        assert_eq!(HashSet::from([3, 4, 5, 6]), rolls);
+    }
+}
@@ -0,0 +1,48 @@
+use std::fs::{self, File};
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+
+use crate::error::{Error, Result};
+
+/// Returns a buffered reader over the puzzle input file.
+///
+/// # Arguments
+///
+/// * `filepath` - The full path to the puzzle input file.
+pub fn open(filepath: &str) -> Result<BufReader<File>> {
+    let path = Path::new(filepath);
+    let file = File::open(path).map_err(|err| Error::io(path, err))?;
+    return Ok(BufReader::new(file));
+}
+
+/// Returns the whole puzzle input as text, reading from stdin when the path is `-`.
+///
+/// # Arguments
+///
+/// * `path` - The path to the puzzle input file, or `-` for stdin.
+pub fn read_to_string(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut text = String::new();
+        io::stdin().lock().read_to_string(&mut text)?;
+        return Ok(text);
+    }
+    return fs::read_to_string(path).map_err(|err| Error::io(path, err));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufRead;
+
+    #[test]
+    fn test_open() {
+        let reader = open("src/y2022/day_4/examples/example.txt").unwrap();
+        assert_eq!(6, reader.lines().count());
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let error = read_to_string(Path::new("src/y2022/day_4/missing.txt")).unwrap_err();
+        assert!(error.to_string().starts_with("unable to access src/y2022/day_4/missing.txt"));
+    }
+}
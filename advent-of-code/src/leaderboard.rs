@@ -0,0 +1,329 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+
+/// The time a star was earned, as recorded in a leaderboard export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    /// When the star was earned, in seconds since the Unix epoch.
+    pub get_star_ts: u64,
+    /// The order the star was earned in across the whole event, breaking ties between equal times.
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+/// One member of a private leaderboard.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// The member's display name, or `None` for anonymous users.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    /// When the member earned their last star, in seconds since the Unix epoch.
+    #[serde(default)]
+    pub last_star_ts: u64,
+    /// The stars earned, keyed by day and then part, both as strings.
+    #[serde(default)]
+    completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+impl Member {
+    /// Returns the member's name as the Advent of Code website shows it.
+    pub fn display_name(&self) -> String {
+        return match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        };
+    }
+
+    /// Returns the star earned for a part of a day's puzzle, if the member has earned it.
+    ///
+    /// # Arguments
+    ///
+    /// * `day` - the day of the event.
+    /// * `part` - the part of the puzzle.
+    pub fn star(&self, day: u8, part: u8) -> Option<&Star> {
+        return self.completion_day_level.get(&day.to_string())?.get(&part.to_string());
+    }
+
+    /// Returns the number of seconds between earning the first and second star of a day.
+    ///
+    /// # Arguments
+    ///
+    /// * `day` - the day of the event.
+    pub fn part_two_delta(&self, day: u8) -> Option<u64> {
+        let part_one = self.star(day, 1)?;
+        let part_two = self.star(day, 2)?;
+        return Some(part_two.get_star_ts.saturating_sub(part_one.get_star_ts));
+    }
+}
+
+/// A private leaderboard, as exported from its JSON API at
+/// `https://adventofcode.com/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    /// The year of the event, such as `"2022"`.
+    pub event: String,
+    pub owner_id: u64,
+    /// The members, keyed by their id as a string.
+    members: BTreeMap<String, Member>,
+}
+
+impl Leaderboard {
+    /// Returns the leaderboard loaded from an exported JSON file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the exported JSON.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).map_err(|err| Error::io(path, err))?;
+        return Self::from_json(&text);
+    }
+
+    /// Returns the leaderboard parsed from exported JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The JSON text.
+    pub fn from_json(text: &str) -> Result<Self> {
+        return serde_json::from_str(text).map_err(|err| {
+            let offending = text.lines().nth(err.line().saturating_sub(1)).unwrap_or("");
+            return Error::parse(offending, &err.to_string()).at_line(err.line());
+        });
+    }
+
+    /// Returns the members in leaderboard order: by local score, then by stars, then by who
+    /// earned their last star first.
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|member| {
+            (
+                std::cmp::Reverse(member.local_score),
+                std::cmp::Reverse(member.stars),
+                member.last_star_ts,
+                member.id,
+            )
+        });
+        return members;
+    }
+
+    /// Returns every day on which at least one member earned a star, in order.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .members
+            .values()
+            .flat_map(|member| member.completion_day_level.keys())
+            .filter_map(|day| day.parse().ok())
+            .collect();
+        days.sort();
+        days.dedup();
+        return days;
+    }
+
+    /// Returns when a day's puzzle was released, midnight US Eastern Time on that day of
+    /// December, in seconds since the Unix epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `day` - the day of the event.
+    pub fn unlock_time(&self, day: u8) -> Option<u64> {
+        let year: i64 = self.event.parse().ok()?;
+        let days = days_from_civil(year, 12, day as i64);
+        return u64::try_from(days * 86_400 + 5 * 3_600).ok();
+    }
+}
+
+/// Returns the number of days between 1970-01-01 and a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    return era * 146_097 + day_of_era - 719_468;
+}
+
+/// Returns a number of seconds as `h:mm:ss`.
+fn format_seconds(seconds: u64) -> String {
+    return format!("{}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60);
+}
+
+/// Returns the ranking as a table of rank, local score, stars and name.
+///
+/// # Arguments
+///
+/// * `leaderboard` - the leaderboard to rank.
+pub fn format_ranking(leaderboard: &Leaderboard) -> String {
+    let mut lines: Vec<String> = vec![format!("{:>4}  {:>5}  {:>5}  {}", "Rank", "Score", "Stars", "Member")];
+    for (rank, member) in leaderboard.ranking().into_iter().enumerate() {
+        lines.push(format!(
+            "{:>4}  {:>5}  {:>5}  {}",
+            rank + 1,
+            member.local_score,
+            member.stars,
+            member.display_name()
+        ));
+    }
+    return lines.join("\n");
+}
+
+/// Returns, for each day and member, how long after the puzzle's release each part was solved
+/// and how long part two took after part one.
+///
+/// # Arguments
+///
+/// * `leaderboard` - the leaderboard to report on.
+pub fn format_days(leaderboard: &Leaderboard) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for day in leaderboard.days() {
+        lines.push(format!("Day {}", day));
+        let unlock = leaderboard.unlock_time(day).unwrap_or(0);
+        let elapsed = |star: Option<&Star>| match star {
+            Some(star) => format_seconds(star.get_star_ts.saturating_sub(unlock)),
+            None => String::from("-"),
+        };
+        for member in leaderboard.ranking() {
+            if member.star(day, 1).is_none() {
+                continue;
+            }
+            let delta = match member.part_two_delta(day) {
+                Some(delta) => format!("+{}", format_seconds(delta)),
+                None => String::from("-"),
+            };
+            lines.push(format!(
+                "  {:<24} part 1 {:>10}  part 2 {:>10}  delta {:>10}",
+                member.display_name(),
+                elapsed(member.star(day, 1)),
+                elapsed(member.star(day, 2)),
+                delta
+            ));
+        }
+    }
+    return lines.join("\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2022-12-01 05:00:00 UTC is 1669870800.
+    const EXPORT: &str = r#"{
+  "owner_id": 1,
+  "event": "2022",
+  "members": {
+    "1": {
+      "id": 1,
+      "name": "Ada",
+      "stars": 3,
+      "local_score": 8,
+      "global_score": 0,
+      "last_star_ts": 1669958000,
+      "completion_day_level": {
+        "1": {
+          "1": {"get_star_ts": 1669871100, "star_index": 10},
+          "2": {"get_star_ts": 1669871400, "star_index": 12}
+        },
+        "2": {
+          "1": {"get_star_ts": 1669958000, "star_index": 40}
+        }
+      }
+    },
+    "2": {
+      "id": 2,
+      "name": null,
+      "stars": 2,
+      "local_score": 5,
+      "global_score": 0,
+      "last_star_ts": 1669880000,
+      "completion_day_level": {
+        "1": {
+          "1": {"get_star_ts": 1669871000, "star_index": 8},
+          "2": {"get_star_ts": 1669880000, "star_index": 30}
+        }
+      }
+    },
+    "3": {
+      "id": 3,
+      "name": "Grace",
+      "stars": 0,
+      "local_score": 0,
+      "global_score": 0,
+      "last_star_ts": 0,
+      "completion_day_level": {}
+    }
+  }
+}"#;
+
+    #[test]
+    fn test_from_json() {
+        let leaderboard = Leaderboard::from_json(EXPORT).unwrap();
+        assert_eq!("2022", leaderboard.event);
+        assert_eq!(vec![1, 2], leaderboard.days());
+        let ada = leaderboard.ranking()[0];
+        assert_eq!(Some(1669871400), ada.star(1, 2).map(|star| star.get_star_ts));
+        assert_eq!(None, ada.star(2, 2));
+    }
+
+    #[test]
+    fn test_from_json_error() {
+        let error = Leaderboard::from_json("{\n  \"event\": 2022\n}").unwrap_err();
+        assert!(matches!(error, Error::Parse { line: Some(2), .. }), "{}", error);
+    }
+
+    #[test]
+    fn test_ranking() {
+        let leaderboard = Leaderboard::from_json(EXPORT).unwrap();
+        let names: Vec<String> = leaderboard.ranking().iter().map(|member| member.display_name()).collect();
+        assert_eq!(vec!["Ada", "(anonymous user #2)", "Grace"], names);
+    }
+
+    #[test]
+    fn test_part_two_delta() {
+        let leaderboard = Leaderboard::from_json(EXPORT).unwrap();
+        let ranking = leaderboard.ranking();
+        assert_eq!(Some(300), ranking[0].part_two_delta(1));
+        assert_eq!(Some(9000), ranking[1].part_two_delta(1));
+        assert_eq!(None, ranking[0].part_two_delta(2));
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        let leaderboard = Leaderboard::from_json(EXPORT).unwrap();
+        assert_eq!(Some(1669870800), leaderboard.unlock_time(1));
+        assert_eq!(Some(1669870800 + 24 * 86_400), leaderboard.unlock_time(25));
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!(19_052, days_from_civil(2022, 3, 1));
+    }
+
+    #[test]
+    fn test_format_ranking() {
+        let leaderboard = Leaderboard::from_json(EXPORT).unwrap();
+        assert_eq!(
+            "Rank  Score  Stars  Member
+   1      8      3  Ada
+   2      5      2  (anonymous user #2)
+   3      0      0  Grace",
+            format_ranking(&leaderboard)
+        );
+    }
+
+    #[test]
+    fn test_format_days() {
+        let leaderboard = Leaderboard::from_json(EXPORT).unwrap();
+        let days = format_days(&leaderboard);
+        let lines: Vec<&str> = days.lines().collect();
+        assert_eq!("Day 1", lines[0]);
+        assert_eq!(
+            "  Ada                      part 1    0:05:00  part 2    0:10:00  delta   +0:05:00",
+            lines[1]
+        );
+        assert_eq!("Day 2", lines[3]);
+        assert!(lines[4].ends_with("part 2          -  delta          -"), "{}", lines[4]);
+        assert_eq!(5, lines.len());
+    }
+}
@@ -0,0 +1,44 @@
+//! Solutions to the Advent of Code puzzles.
+//!
+//! Each event's days live in a module named after its year, such as [`y2022`]. Every solved day
+//! implements [`Solution`] and is listed in [`DAYS`], so a day can be run without knowing the
+//! types it uses:
+//!
+//! ```
+//! use advent_of_code::find_day;
+//!
+//! let day = find_day(2022, 4).unwrap();
+//! let solution = day.parse("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n")?;
+//! assert_eq!(solution.part_one(), "2");
+//! assert_eq!(solution.part_two(), "4");
+//! # Ok::<(), advent_of_code::Error>(())
+//! ```
+#![allow(clippy::needless_return)]
+#![cfg_attr(test, allow(clippy::bool_assert_comparison))]
+
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod config;
+pub mod error;
+pub mod generate;
+pub mod input;
+pub mod leaderboard;
+pub mod percentile;
+pub mod provider;
+pub mod reference;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+#[cfg(test)]
+pub(crate) mod stand_in;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+pub mod y2022;
+
+pub use error::{Error, Result};
+pub use registry::{find_day, latest_year, DAYS};
+pub use solution::{Day, Solution};
@@ -1,56 +1,372 @@
-mod day_1;
-mod day_2;
-mod day_3;
-mod day_4;
+#![allow(clippy::needless_return)]
+#![cfg_attr(test, allow(clippy::bool_assert_comparison))]
 
-use day_1::Elves;
-use day_2::{Game, GameTwo};
-use day_3::{ElfGroups, RucksackPriorities};
-use day_4::Sections;
+mod cli;
 
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-fn day_1_solution() {
-    let elves: Elves = Elves::load_elves(String::from("src/day_1/source.txt"));
-    println!("{:#?}", elves);
-    println!("Number of Elves: {}", elves.get_total_elves());
-    let elf_id = elves.get_elf_id_with_most_calories();
-    println!("Elf with the most calories: {}", elf_id);
-    println!("Elf calories: {}", elves.get_elf_total_calories(elf_id));
+use clap::Parser;
 
-    let n = 3;
-    let top_n_elf_ids: Vec<i32> = elves.get_top_n_calories_elf_ids(n);
-    println!("Top {} Elves: {:#?}", n, top_n_elf_ids);
-    println!("Top {} Elves Calories: {:#?}", n, elves.get_total_calories_for_elf_ids(top_n_elf_ids));
-    
+use advent_of_code::answers::Answers;
+use advent_of_code::bench::{self, DayTimings};
+use advent_of_code::cache::AnswerCache;
+use advent_of_code::config::{Config, CONFIG_VAR};
+use advent_of_code::generate;
+use advent_of_code::leaderboard::{self, Leaderboard};
+use advent_of_code::provider::{HttpFetcher, InputProvider, SESSION_VAR};
+use advent_of_code::registry;
+use advent_of_code::report;
+use advent_of_code::submit::{self, HttpSubmitter, Outcome, Submissions, Verdict};
+use advent_of_code::verify::{self, Status};
+use advent_of_code::watch::{self, Snapshot, Watcher};
+use advent_of_code::{find_day, input, latest_year, runner, scaffold, Day, Error, Result};
+use cli::{BenchArgs, Cli, Command, GenerateArgs, LeaderboardArgs, NewDayArgs, ReportArgs, RunArgs, SubmitArgs, VerifyArgs, WatchArgs};
+
+/// Returns the configuration from `aoc.toml`, or the file named by `--config` or `AOC_CONFIG`,
+/// with the environment and the global command line flags applied on top.
+fn config(cli: &Cli) -> Result<Config> {
+    let path = match (&cli.config, env::var_os(CONFIG_VAR)) {
+        (Some(path), _) => path.clone(),
+        (None, Some(path)) => path.into(),
+        (None, None) => Config::default_path(),
+    };
+    let mut config = Config::load(&path)?.with_env(|name| env::var(name).ok())?;
+    if let Some(inputs) = &cli.inputs {
+        config.inputs = inputs.clone();
+    }
+    return Ok(config);
+}
+
+/// Returns the input provider for the configured inputs directory, downloading missing inputs
+/// when a session is available.
+fn inputs(config: &Config) -> InputProvider {
+    let provider = InputProvider::new(&config.inputs);
+    return match &config.session {
+        Some(session) => provider.with_fetcher(Box::new(HttpFetcher::new(session))),
+        None => provider,
+    };
+}
+
+/// Returns the registered day, printing an error if it has not been solved. The year defaults to
+/// the configured year, then to the most recent event with a solved day.
+fn find_registered_day(config: &Config, year: Option<u16>, number: u8) -> Option<&'static Day> {
+    let year = year.or(config.year).unwrap_or_else(latest_year);
+    let day = find_day(year, number);
+    if day.is_none() {
+        eprintln!("{} day {} has not been solved yet, see `list` for the available days.", year, number);
+    }
+    return day;
+}
+
+/// Runs every registered day and returns an error if any day's input could not be parsed.
+fn run_all(config: &Config, args: &RunArgs, jobs: usize) -> Result<()> {
+    let inputs = inputs(config);
+    let year = args.year.or(config.year);
+    let format = args.format.unwrap_or(config.format);
+    let failed = if args.no_cache {
+        runner::run_all(&inputs, year, args.part, format, jobs, None)
+    } else {
+        let path = &config.cache;
+        let cache = AnswerCache::load(path)?;
+        let failed = runner::run_all(&inputs, year, args.part, format, jobs, Some(&cache));
+        cache.save(path)?;
+        failed
+    };
+    if failed > 0 {
+        return Err(Error::Validation(format!("{} days could not be solved", failed)));
+    }
+    return Ok(());
+}
+
+fn run(config: &Config, args: RunArgs) -> ExitCode {
+    let result = if args.all {
+        let jobs = match args.jobs {
+            Some(jobs) => jobs as usize,
+            None => thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+        };
+        run_all(config, &args, jobs)
+    } else {
+        let number = args.day.expect("clap requires --day without --all");
+        let Some(day) = find_registered_day(config, args.year, number) else {
+            return ExitCode::FAILURE;
+        };
+        let text = match args.input {
+            Some(path) => input::read_to_string(&path),
+            None => inputs(config).get(day.year, day.number),
+        };
+        let format = args.format.unwrap_or(config.format);
+        text.and_then(|text| runner::run_day(day, &text, args.part, format))
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+fn verify(config: &Config, args: VerifyArgs) -> ExitCode {
+    let path = args.answers.unwrap_or_else(|| config.answers.clone());
+    let outcomes = match Answers::load(&path).map(|answers| verify::verify(&answers, &inputs(config))) {
+        Ok(outcomes) => outcomes,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for outcome in outcomes.iter() {
+        println!("{}", outcome);
+    }
+    let passed = outcomes.iter().filter(|outcome| outcome.status == Status::Pass).count();
+    let missing = outcomes.iter().filter(|outcome| outcome.status == Status::Missing).count();
+    let skipped = outcomes.iter().filter(|outcome| outcome.status == Status::NoInput).count();
+    let failed = outcomes.len() - passed - missing - skipped;
+    println!("{} passed, {} failed, {} missing, {} without input", passed, failed, missing, skipped);
+
+    if failed > 0 {
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+fn bench(config: &Config, args: BenchArgs) -> ExitCode {
+    let days: Vec<&Day> = match args.day {
+        Some(number) => match find_registered_day(config, args.year, number) {
+            Some(day) => vec![day],
+            None => return ExitCode::FAILURE,
+        },
+        None => registry::days(args.year.or(config.year)).collect(),
+    };
+
+    let inputs = inputs(config);
+    let iterations = args.iterations.unwrap_or(config.iterations);
+    let mut failed = false;
+    println!("{:>4}  {:>3}  {:<8}  {:>12}  {:>12}  {:>12}", "Year", "Day", "Phase", "Min", "Median", "P95");
+    for day in days {
+        let timings = match bench_day(&inputs, day, iterations) {
+            Ok(timings) => timings,
+            // Without --day, days whose input is not cached are skipped like `run --all` does.
+            Err(err) if err.is_not_found() && args.day.is_none() => {
+                eprintln!("Skipping {} day {}, its input is not cached.", day.year, day.number);
+                continue;
+            }
+            Err(err) => {
+                eprintln!("error: {} day {}: {}", day.year, day.number, err);
+                failed = true;
+                continue;
+            }
+        };
+        for (phase, timing) in timings.phases() {
+            println!(
+                "{:>4}  {:>3}  {:<8}  {:>12.2?}  {:>12.2?}  {:>12.2?}",
+                timings.year,
+                timings.day,
+                phase,
+                timing.min(),
+                timing.median(),
+                timing.percentile(95)
+            );
+        }
+    }
+    if failed {
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+fn bench_day(inputs: &InputProvider, day: &Day, iterations: u32) -> Result<DayTimings> {
+    let text = inputs.get(day.year, day.number)?;
+    return bench::bench_day(day, &text, iterations as usize);
 }
 
-fn day_2_solution() {
-    let game: Game = Game::load_strategy_game("src/day_2/strategy_guide.txt");
-    println!("{:#?}", game);
-    println!("Current Score: {}", game.get_current_score());
+fn new_day(config: &Config, args: NewDayArgs) -> ExitCode {
+    let year = args.year.or(config.year).unwrap_or_else(latest_year);
+    if let Some(day) = find_day(year, args.day) {
+        eprintln!("{} day {} is already registered as {:?}.", day.year, day.number, day.title);
+        return ExitCode::FAILURE;
+    }
+    let title = args.title.unwrap_or_else(|| format!("Day {}", args.day));
+    let new_day = match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), &config.inputs, year, args.day, &title) {
+        Ok(new_day) => new_day,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
 
-    let game: GameTwo = GameTwo::load_strategy_game("src/day_2/strategy_guide.txt");
-    println!("{:#?}", game);
-    println!("Current Score: {}", game.get_current_score());
+    println!("Registered {} day {} as {}, created:", year, args.day, new_day.type_name);
+    for file in new_day.files.iter() {
+        println!("  {}", file.display());
+    }
+    return ExitCode::SUCCESS;
 }
 
-fn day_3_solution() {
-    let rsp = RucksackPriorities::load_from_file("src/day_3/rucksack_items.txt");
-    println!("{:#?}", rsp);
+fn submit(config: &Config, args: SubmitArgs) -> ExitCode {
+    let Some(day) = find_registered_day(config, args.year, args.day) else {
+        return ExitCode::FAILURE;
+    };
+    let Some(submitter) = config.session.as_deref().map(HttpSubmitter::new) else {
+        eprintln!("Set {} to the session cookie of a logged in user to submit answers.", SESSION_VAR);
+        return ExitCode::FAILURE;
+    };
+    let path = &config.submissions;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
 
-    let mut eg = ElfGroups::load_from_file("src/day_3/rucksack_items.txt");
-    println!("Priority Points: {:#?}", eg.get_priority_points());
+    let result = inputs(config).get(day.year, day.number).and_then(|text| {
+        let solution = day.parse(&text)?;
+        let answer = runner::solve(solution.as_ref(), args.part);
+        let mut submissions = Submissions::load(path)?;
+        let outcome = submit::submit(&submitter, &mut submissions, day.year, day.number, args.part, &answer, now)?;
+        submissions.save(path)?;
+        return Ok((answer, outcome));
+    });
+    let (answer, outcome) = match result {
+        Ok(submitted) => submitted,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match outcome {
+        Outcome::Refused(refusal) => {
+            eprintln!("Not submitting {} for {} day {} part {}: {}.", answer, day.year, day.number, args.part, refusal);
+            return ExitCode::FAILURE;
+        }
+        Outcome::Submitted(response) => {
+            println!("{} day {} part {}: {} is {}.", day.year, day.number, args.part, answer, response.verdict);
+            if let Some(wait) = response.wait {
+                println!("Wait {}s before submitting again.", wait);
+            }
+            if response.verdict != Verdict::Correct {
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    return ExitCode::SUCCESS;
 }
 
-fn day_4_solution() {
-    let sections = Sections::from_file("src/day_4/section_assignments.txt");
-    println!("{:#?}", sections);
+fn watch(config: &Config, args: WatchArgs) -> ExitCode {
+    let Some(day) = find_registered_day(config, args.year, args.day) else {
+        return ExitCode::FAILURE;
+    };
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let result: Result<()> = watch::example_files(root, day).and_then(|mut files| {
+        files.insert(0, inputs(config).path(day.year, day.number));
+        let watcher = Watcher::new(&files)?;
+        println!("Watching {} day {}: {}, press Ctrl-C to stop.", day.year, day.number, day.title);
+
+        let mut previous: Option<Snapshot> = None;
+        loop {
+            let snapshot = Snapshot::take(day, &files);
+            for line in snapshot.diff(previous.as_ref(), root) {
+                println!("{}", line);
+            }
+            previous = Some(snapshot);
+            let changed = watcher.wait()?;
+            let names: Vec<String> = changed
+                .iter()
+                .map(|file| file.strip_prefix(root).unwrap_or(file).display().to_string())
+                .collect();
+            println!("\nChanged: {}", names.join(", "));
+        }
+    });
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+    }
+    return ExitCode::FAILURE;
 }
 
+fn report(config: &Config, args: ReportArgs) -> ExitCode {
+    let result = Answers::load(&config.answers).and_then(|answers| {
+        let rows = report::rows(&answers, &inputs(config))?;
+        if !args.write {
+            println!("{}", report::format_table(&rows, true));
+            return Ok(());
+        }
+        let readme = args.readme.unwrap_or_else(report::default_readme);
+        report::write_readme(&readme, &rows, args.runtimes)?;
+        println!("Updated {}", readme.display());
+        return Ok(());
+    });
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+fn show_leaderboard(args: LeaderboardArgs) -> ExitCode {
+    let leaderboard = match Leaderboard::load(&args.path) {
+        Ok(leaderboard) => leaderboard,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Advent of Code {}\n", leaderboard.event);
+    println!("{}\n", leaderboard::format_ranking(&leaderboard));
+    println!("Time to solve each part after release, and part two after part one:");
+    println!("{}", leaderboard::format_days(&leaderboard));
+    return ExitCode::SUCCESS;
+}
+
+fn generate(config: &Config, args: GenerateArgs) -> ExitCode {
+    let year = args.year.or(config.year).unwrap_or_else(latest_year);
+    let seed = match args.seed {
+        Some(seed) => seed,
+        None => {
+            let seed = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_nanos() as u64);
+            eprintln!("Generating {} day {} with --seed {}", year, args.day, seed);
+            seed
+        }
+    };
+    let result = generate::generate(year, args.day, args.size, seed).and_then(|text| match &args.output {
+        Some(path) => fs::write(path, text).map_err(|err| Error::io(path, err)),
+        None => {
+            print!("{}", text);
+            Ok(())
+        }
+    });
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
 
-fn main() {
-    // day_1_solution();
-    // day_2_solution();
-    // day_3_solution();
-    day_4_solution();
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let config = match config(&cli) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    return match cli.command {
+        Command::Run(args) => run(&config, args),
+        Command::List => {
+            runner::list(&inputs(&config));
+            ExitCode::SUCCESS
+        }
+        Command::Verify(args) => verify(&config, args),
+        Command::Bench(args) => bench(&config, args),
+        Command::NewDay(args) => new_day(&config, args),
+        Command::Submit(args) => submit(&config, args),
+        Command::Watch(args) => watch(&config, args),
+        Command::Report(args) => report(&config, args),
+        Command::Leaderboard(args) => show_leaderboard(args),
+        Command::Generate(args) => generate(&config, args),
+    };
 }
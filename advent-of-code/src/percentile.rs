@@ -0,0 +1,38 @@
+//! Percentiles of sorted samples, shared by the benchmark timings and the puzzle statistics.
+
+/// Returns the sample that the given percentage of samples are at most, by the nearest-rank
+/// method. The 0th percentile is the smallest sample.
+///
+/// # Arguments
+///
+/// * `sorted` - the samples, sorted from smallest to largest. Must not be empty.
+/// * `percent` - the percentile, from 0 to 100. Anything larger is treated as 100.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::percentile::nearest_rank;
+///
+/// assert_eq!(20, nearest_rank(&[10, 20, 30, 40], 50));
+/// assert_eq!(30, nearest_rank(&[10, 20, 30, 40], 51));
+/// ```
+pub fn nearest_rank<T: Copy>(sorted: &[T], percent: usize) -> T {
+    let rank = (percent.min(100) * sorted.len()).div_ceil(100);
+    return sorted[rank.saturating_sub(1)];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_rank() {
+        let samples = [4000, 6000, 10000, 11000, 24000];
+        assert_eq!(4000, nearest_rank(&samples, 0));
+        assert_eq!(4000, nearest_rank(&samples, 20));
+        assert_eq!(6000, nearest_rank(&samples, 21));
+        assert_eq!(24000, nearest_rank(&samples, 100));
+        assert_eq!(24000, nearest_rank(&samples, 250));
+        assert_eq!(7, nearest_rank(&[7], 95));
+    }
+}
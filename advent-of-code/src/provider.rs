@@ -0,0 +1,243 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+/// The Advent of Code website, which serves each user their own puzzle inputs.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The environment variable holding the session cookie used to download puzzle inputs.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Somewhere puzzle inputs can be downloaded from when they are not in the cache.
+pub trait Fetcher: Send + Sync {
+    /// Returns the puzzle input for a day of an event.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - the year of the event.
+    /// * `day` - the day of the event.
+    fn fetch(&self, year: u16, day: u8) -> Result<String>;
+}
+
+/// Downloads puzzle inputs from the Advent of Code website, or anything serving the same paths.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpFetcher {
+    /// Returns a fetcher for the Advent of Code website.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - the value of the `session` cookie of a logged in user.
+    pub fn new(session: &str) -> Self {
+        return Self::with_base_url(DEFAULT_BASE_URL, session);
+    }
+
+    /// Returns a fetcher for a server other than the Advent of Code website.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - the URL that `/<year>/day/<day>/input` is relative to.
+    /// * `session` - the value of the `session` cookie of a logged in user.
+    pub fn with_base_url(base_url: &str, session: &str) -> Self {
+        return Self {
+            base_url: String::from(base_url.trim_end_matches('/')),
+            session: String::from(session.trim()),
+            agent: ureq::Agent::new_with_defaults(),
+        };
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let http_error = |err: ureq::Error| Error::Http {
+            url: url.clone(),
+            message: err.to_string(),
+        };
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", "github.com/JHowell45/advent-of-code-rust")
+            .call()
+            .map_err(http_error)?;
+        return response.body_mut().read_to_string().map_err(http_error);
+    }
+}
+
+/// Looks up puzzle inputs in an on-disk cache at `<cache_dir>/<year>/<day>.txt`, downloading
+/// any that are missing.
+pub struct InputProvider {
+    cache_dir: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputProvider {
+    /// Returns a provider that only reads inputs already in the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_dir` - the directory holding a folder of inputs for each year.
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        return Self {
+            cache_dir: cache_dir.into(),
+            fetcher: None,
+        };
+    }
+
+    /// Returns the crate's `inputs` directory.
+    pub fn default_cache_dir() -> PathBuf {
+        return Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+    }
+
+    /// Returns the provider, downloading inputs missing from the cache with `fetcher`.
+    ///
+    /// # Arguments
+    ///
+    /// * `fetcher` - where to download missing inputs from.
+    pub fn with_fetcher(mut self, fetcher: Box<dyn Fetcher>) -> Self {
+        self.fetcher = Some(fetcher);
+        return self;
+    }
+
+    /// Returns where the input for a day is cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - the year of the event.
+    /// * `day` - the day of the event.
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        return self.cache_dir.join(Self::relative_path(year, day));
+    }
+
+    /// Returns where the input for a day is cached, relative to the cache directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - the year of the event.
+    /// * `day` - the day of the event.
+    pub fn relative_path(year: u16, day: u8) -> String {
+        return format!("{}/{}.txt", year, day);
+    }
+
+    /// Returns the input for a day, from the cache if it is there and otherwise from the fetcher.
+    /// Empty cached files are placeholders and count as missing.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - the year of the event.
+    /// * `day` - the day of the event.
+    pub fn get(&self, year: u16, day: u8) -> Result<String> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(text) if !text.is_empty() => return Ok(text),
+            Ok(_) => (),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(Error::io(&path, err)),
+        }
+
+        let Some(fetcher) = &self.fetcher else {
+            let message = format!("no cached input, set {} to download it", SESSION_VAR);
+            return Err(Error::io(&path, io::Error::new(io::ErrorKind::NotFound, message)));
+        };
+        let text = fetcher.fetch(year, day)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| Error::io(parent, err))?;
+        }
+        fs::write(&path, &text).map_err(|err| Error::io(&path, err))?;
+        return Ok(text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::{example_inputs, serve_once};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct CountingFetcher {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, year: u16, day: u8) -> Result<String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            return Ok(format!("{} {}\n", year, day));
+        }
+    }
+
+    fn temp_cache(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("advent-of-code-inputs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        return dir;
+    }
+
+    #[test]
+    fn test_http_fetcher() {
+        let (base_url, server) = serve_once("200 OK", "1000\n2000\n");
+        let fetcher = HttpFetcher::with_base_url(&base_url, "secret\n");
+        assert_eq!("1000\n2000\n", fetcher.fetch(2022, 1).unwrap());
+
+        let request = server.join().unwrap();
+        assert_eq!("GET /2022/day/1/input HTTP/1.1", request.line);
+        assert!(request.has_header("cookie: session=secret"));
+    }
+
+    #[test]
+    fn test_http_fetcher_error_status() {
+        let (base_url, server) = serve_once("400 Bad Request", "Please log in");
+        let fetcher = HttpFetcher::with_base_url(&base_url, "expired");
+        let error = fetcher.fetch(2022, 1).unwrap_err();
+        assert!(matches!(&error, Error::Http { url, .. } if url.ends_with("/2022/day/1/input")), "{}", error);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_provider_caches_fetched_input() {
+        let cache = temp_cache("fetch");
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = InputProvider::new(&cache).with_fetcher(Box::new(CountingFetcher { calls: calls.clone() }));
+
+        assert_eq!("2022 5\n", provider.get(2022, 5).unwrap());
+        assert_eq!("2022 5\n", provider.get(2022, 5).unwrap());
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+        assert_eq!("2022 5\n", fs::read_to_string(cache.join("2022/5.txt")).unwrap());
+        fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_provider_refetches_empty_placeholder() {
+        let cache = temp_cache("placeholder");
+        fs::create_dir_all(cache.join("2022")).unwrap();
+        fs::write(cache.join("2022/6.txt"), "").unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = InputProvider::new(&cache).with_fetcher(Box::new(CountingFetcher { calls: calls.clone() }));
+
+        assert_eq!("2022 6\n", provider.get(2022, 6).unwrap());
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+        fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_provider_without_fetcher() {
+        let provider = InputProvider::new(temp_cache("offline"));
+        let error = provider.get(2022, 7).unwrap_err();
+        assert!(error.to_string().contains("AOC_SESSION"), "{}", error);
+    }
+
+    #[test]
+    fn test_provider_reads_cached_inputs() {
+        let cache = example_inputs("provider");
+        let provider = InputProvider::new(&cache);
+        assert!(provider.get(2022, 4).unwrap().starts_with("2-4,6-8\n"));
+        fs::remove_dir_all(&cache).unwrap();
+    }
+}
@@ -0,0 +1,269 @@
+//! Deliberately naive solutions, written for obviousness rather than speed, that the real
+//! solutions are cross-checked against on generated inputs.
+
+use std::collections::BTreeSet;
+
+use crate::error::{Error, Result};
+
+/// Returns the answers to both parts of a day's puzzle from its reference solution, or `None` if
+/// the day does not have one.
+///
+/// # Arguments
+///
+/// * `year` - the year of the event.
+/// * `day` - the day of the event.
+/// * `text` - the full text of the puzzle input.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::reference;
+///
+/// let answers = reference::solve(2022, 4, "2-4,6-8\n2-8,3-7\n5-7,7-9\n").unwrap()?;
+/// assert_eq!([String::from("1"), String::from("2")], answers);
+/// # Ok::<(), advent_of_code::Error>(())
+/// ```
+pub fn solve(year: u16, day: u8, text: &str) -> Option<Result<[String; 2]>> {
+    return match (year, day) {
+        (2022, 1) => Some(calories(text)),
+        (2022, 2) => Some(rock_paper_scissors(text)),
+        (2022, 3) => Some(rucksacks(text)),
+        (2022, 4) => Some(camp_cleanup(text)),
+        _ => None,
+    };
+}
+
+/// Returns the answers to both parts as strings.
+fn answers(part_one: u64, part_two: u64) -> [String; 2] {
+    return [part_one.to_string(), part_two.to_string()];
+}
+
+/// 2022 day 1: totals every elf's calories and sorts all of the totals.
+///
+/// # Arguments
+///
+/// * `text` - the calories carried by each elf, with a blank line between elves.
+pub fn calories(text: &str) -> Result<[String; 2]> {
+    let mut totals: Vec<u64> = Vec::new();
+    let mut total: Option<u64> = None;
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            totals.extend(total.take());
+            continue;
+        }
+        let calories: u64 = line
+            .trim()
+            .parse()
+            .map_err(|_| Error::parse(line, "expected the calories of a food item").at_line(index + 1))?;
+        total = Some(total.unwrap_or(0) + calories);
+    }
+    totals.extend(total);
+    totals.sort();
+    totals.reverse();
+    return Ok(answers(totals.first().copied().unwrap_or(0), totals.iter().take(3).sum()));
+}
+
+/// 2022 day 2: plays every hand against the opponent's to find which one gives each result.
+///
+/// # Arguments
+///
+/// * `text` - the strategy guide, one round such as `A Y` per line.
+pub fn rock_paper_scissors(text: &str) -> Result<[String; 2]> {
+    // Rock, paper and scissors are 0, 1 and 2, and each hand beats the one before it.
+    let beats = |hand: u64, other: u64| hand == (other + 1) % 3;
+    let result = |player: u64, opponent: u64| match (beats(player, opponent), beats(opponent, player)) {
+        (true, _) => 2,
+        (_, true) => 0,
+        _ => 1,
+    };
+
+    let mut scores = [0, 0];
+    for (index, line) in text.lines().enumerate() {
+        let columns = line.split_once(' ').and_then(|(opponent, second)| {
+            let opponent = ["A", "B", "C"].iter().position(|hand| *hand == opponent)? as u64;
+            let second = ["X", "Y", "Z"].iter().position(|column| *column == second)? as u64;
+            return Some((opponent, second));
+        });
+        let Some((opponent, second)) = columns else {
+            return Err(Error::parse(line, "expected a round such as A Y").at_line(index + 1));
+        };
+
+        scores[0] += second + 1 + 3 * result(second, opponent);
+        for player in 0..3 {
+            if result(player, opponent) == second {
+                scores[1] += player + 1 + 3 * second;
+            }
+        }
+    }
+    return Ok(answers(scores[0], scores[1]));
+}
+
+/// Returns the priority of a rucksack item.
+fn priority(item: char) -> Option<u64> {
+    return match item {
+        'a'..='z' => Some(item as u64 - 'a' as u64 + 1),
+        'A'..='Z' => Some(item as u64 - 'A' as u64 + 27),
+        _ => None,
+    };
+}
+
+/// 2022 day 3: compares every item with every other item to find the shared ones.
+///
+/// # Arguments
+///
+/// * `text` - the rucksacks, one line of items per rucksack.
+pub fn rucksacks(text: &str) -> Result<[String; 2]> {
+    let lines: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+    for (index, line) in text.lines().enumerate() {
+        if !line.len().is_multiple_of(2) || !line.chars().all(|item| priority(item).is_some()) {
+            return Err(Error::parse(line, "expected an even number of letters").at_line(index + 1));
+        }
+    }
+
+    let mut total = 0;
+    for items in lines.iter() {
+        let (left, right) = items.split_at(items.len() / 2);
+        let mut shared: Vec<char> = Vec::new();
+        for l in left.iter() {
+            for r in right.iter() {
+                if l == r && !shared.contains(l) {
+                    shared.push(*l);
+                }
+            }
+        }
+        total += shared.into_iter().filter_map(priority).sum::<u64>();
+    }
+
+    let mut badges = 0;
+    for group in lines.chunks(3) {
+        let [first, second, third] = group else {
+            return Err(Error::Validation(String::from("expected the rucksacks in groups of three")));
+        };
+        let mut shared: Vec<char> = Vec::new();
+        for a in first.iter() {
+            for b in second.iter() {
+                for c in third.iter() {
+                    if a == b && b == c && !shared.contains(a) {
+                        shared.push(*a);
+                    }
+                }
+            }
+        }
+        badges += shared.into_iter().filter_map(priority).sum::<u64>();
+    }
+    return Ok(answers(total, badges));
+}
+
+/// Returns the start and end of a range such as `2-4`, or `None` unless both are numbers and the
+/// range starts before it ends.
+fn range(text: &str) -> Option<(u64, u64)> {
+    let (start, end) = text.split_once('-')?;
+    let (start, end): (u64, u64) = (start.parse().ok()?, end.parse().ok()?);
+    return (start <= end).then_some((start, end));
+}
+
+/// 2022 day 4: lists every section each elf is assigned and compares the two sets.
+///
+/// # Arguments
+///
+/// * `text` - the pairs of section assignments, such as `2-4,6-8`.
+pub fn camp_cleanup(text: &str) -> Result<[String; 2]> {
+    let mut counts = [0, 0];
+    for (index, line) in text.lines().enumerate() {
+        let ranges = line.split_once(',').and_then(|(first, second)| Some((range(first)?, range(second)?)));
+        let Some(((a, b), (c, d))) = ranges else {
+            return Err(Error::parse(line, "expected two ranges such as 2-4,6-8").at_line(index + 1));
+        };
+        let first: BTreeSet<u64> = (a..=b).collect();
+        let second: BTreeSet<u64> = (c..=d).collect();
+        if first.is_subset(&second) || second.is_subset(&first) {
+            counts[0] += 1;
+        }
+        if !first.is_disjoint(&second) {
+            counts[1] += 1;
+        }
+    }
+    return Ok(answers(counts[0], counts[1]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::{generate, Rng};
+    use crate::y2022::day_4::Section;
+    use crate::{find_day, DAYS};
+
+    /// Solves a generated input with both the real and the reference solution, and returns a
+    /// description of the first disagreement.
+    fn compare(year: u16, day: u8, text: &str) -> std::result::Result<(), String> {
+        let solution = find_day(year, day).unwrap().parse(text).map_err(|err| err.to_string())?;
+        let expected = solve(year, day, text).unwrap().map_err(|err| err.to_string())?;
+        let actual = [solution.part_one(), solution.part_two()];
+        if actual != expected {
+            return Err(format!("{} day {}: expected {:?}, got {:?} for input:\n{}", year, day, expected, actual, text));
+        }
+        return Ok(());
+    }
+
+    #[test]
+    fn test_examples() {
+        let cases = [
+            (1, "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n", ["24000", "45000"]),
+            (2, "A Y\nB X\nC Z\n", ["15", "12"]),
+            (
+                3,
+                "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\n\
+                 wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw\n",
+                ["157", "70"],
+            ),
+            (4, "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n", ["2", "4"]),
+        ];
+        for (day, text, expected) in cases {
+            assert_eq!(expected.map(String::from), solve(2022, day, text).unwrap().unwrap(), "day {}", day);
+        }
+    }
+
+    #[test]
+    fn test_camp_cleanup_rejects_malformed_ranges() {
+        for line in ["2-4,6-x,8", "5-3,1-2", "2-4,8-6", "2-4-6,8", "2-4", "2-4,6-8,1-1", "-4,6-8"] {
+            assert!(camp_cleanup(line).is_err(), "{}", line);
+            assert!(Section::from_line(line).is_err(), "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_every_generated_day_has_a_reference() {
+        // Newly scaffolded days have neither an input generator nor a reference until they are solved.
+        for day in DAYS.iter().filter(|day| generate(day.year, day.number, 1, 0).is_ok()) {
+            assert!(solve(day.year, day.number, "").is_some(), "{} day {}", day.year, day.number);
+        }
+    }
+
+    #[test]
+    fn test_matches_solutions_on_generated_inputs() {
+        let mut rng = Rng::new(2022);
+        for _ in 0..50 {
+            let seed = rng.next_u64();
+            let size = rng.between(1, 60) as usize;
+            for day in 1..=4 {
+                compare(2022, day, &generate(2022, day, size, seed).unwrap()).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_section_containment_matches_sets() {
+        for a in 1..=8u8 {
+            for b in a..=8 {
+                for c in 1..=8u8 {
+                    for d in c..=8 {
+                        let line = format!("{}-{},{}-{}", a, b, c, d);
+                        let section = Section::from_line(&line).unwrap();
+                        let counts = [section.fully_contains(), section.partial_contains()].map(|hit| (hit as u8).to_string());
+                        assert_eq!(camp_cleanup(&line).unwrap(), counts, "{}", line);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::solution::Day;
+use crate::y2022::day_1::Elves;
+use crate::y2022::day_2::StrategyGuide;
+use crate::y2022::day_3::Rucksacks;
+use crate::y2022::day_4::Sections;
+
+/// Every solved day, in year and then day order. New days are added here to be picked up by the
+/// runner.
+pub const DAYS: &[Day] = &[
+    Day::new::<Elves>(2022, 1, "Calorie Counting"),
+    Day::new::<StrategyGuide>(2022, 2, "Rock Paper Scissors"),
+    Day::new::<Rucksacks>(2022, 3, "Rucksack Reorganization"),
+    Day::new::<Sections>(2022, 4, "Camp Cleanup"),
+];
+
+/// Returns the registered day with the given year and number, if it has been solved.
+///
+/// # Arguments
+///
+/// * `year` - the year of the event.
+/// * `number` - the day of the event.
+pub fn find_day(year: u16, number: u8) -> Option<&'static Day> {
+    return DAYS.iter().find(|day| day.year == year && day.number == number);
+}
+
+/// Returns every registered day of an event, or of every event when `year` is `None`.
+///
+/// # Arguments
+///
+/// * `year` - the year of the event.
+pub fn days(year: Option<u16>) -> impl Iterator<Item = &'static Day> {
+    return DAYS.iter().filter(move |day| year.is_none_or(|year| day.year == year));
+}
+
+/// Returns the most recent event with a registered day, used when no year is given.
+pub fn latest_year() -> u16 {
+    return DAYS.last().expect("at least one day is registered").year;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_in_order() {
+        for pair in DAYS.windows(2) {
+            assert!(
+                (pair[0].year, pair[0].number) < (pair[1].year, pair[1].number),
+                "{} day {} is out of order",
+                pair[1].year,
+                pair[1].number
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_day() {
+        assert_eq!("Camp Cleanup", find_day(2022, 4).unwrap().title);
+        assert!(find_day(2022, 25).is_none());
+        assert!(find_day(2015, 4).is_none());
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(DAYS.len(), days(None).count());
+        assert!(days(Some(2022)).all(|day| day.year == 2022));
+        assert_eq!(0, days(Some(2015)).count());
+        assert_eq!(2022, latest_year());
+    }
+
+    #[test]
+    fn test_day_four_solution() {
+        let solution = find_day(2022, 4).unwrap().parse("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n").unwrap();
+        assert_eq!("2", solution.part_one());
+        assert_eq!("4", solution.part_two());
+    }
+}
@@ -0,0 +1,228 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::answers::Answers;
+use crate::error::{Error, Result};
+use crate::provider::InputProvider;
+use crate::registry::DAYS;
+use crate::runner::solve;
+use crate::verify::{status, Status};
+
+/// The line that starts the generated section of the README.
+pub const START_MARKER: &str = "<!-- report:start -->";
+
+/// The line that ends the generated section of the README.
+pub const END_MARKER: &str = "<!-- report:end -->";
+
+/// The progress on one registered day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// How each part's answer compares with the answers manifest, or `None` when the day's
+    /// input is not available to check it.
+    pub parts: Option<[Status; 2]>,
+    /// The time taken to parse the input and answer both parts.
+    pub runtime: Option<Duration>,
+}
+
+/// Returns the README at the root of the repository.
+pub fn default_readme() -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR")).join("../README.md");
+}
+
+/// Returns the progress on every registered day, solving each against its input and comparing
+/// the answers with the answers manifest.
+///
+/// # Arguments
+///
+/// * `answers` - the answers manifest.
+/// * `inputs` - where to find each day's puzzle input.
+pub fn rows(answers: &Answers, inputs: &InputProvider) -> Result<Vec<Row>> {
+    let mut rows: Vec<Row> = Vec::new();
+    for day in DAYS.iter() {
+        let mut row = Row {
+            year: day.year,
+            day: day.number,
+            title: day.title,
+            parts: None,
+            runtime: None,
+        };
+        let text = match inputs.get(day.year, day.number) {
+            Ok(text) => text,
+            Err(err) if err.is_not_found() => {
+                rows.push(row);
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+
+        let start = Instant::now();
+        let solution = match day.parse(&text) {
+            Ok(solution) => solution,
+            Err(err) => {
+                let status = Status::Error { message: err.to_string() };
+                row.parts = Some([status.clone(), status]);
+                rows.push(row);
+                continue;
+            }
+        };
+        let computed = [solve(solution.as_ref(), 1), solve(solution.as_ref(), 2)];
+        row.runtime = Some(start.elapsed());
+
+        row.parts = Some([1, 2].map(|part| status(answers, day, part, &computed[part as usize - 1])));
+        rows.push(row);
+    }
+    return Ok(rows);
+}
+
+/// Returns the rows as a Markdown table, followed by the number of stars earned.
+///
+/// # Arguments
+///
+/// * `rows` - the progress on each day, in year and then day order.
+/// * `runtimes` - whether to include the runtime column. Runtimes differ on every run, so a
+///   table without them only changes when the progress does.
+pub fn format_table(rows: &[Row], runtimes: bool) -> String {
+    let mut lines: Vec<String> = match runtimes {
+        true => vec![
+            String::from("| Year | Day | Title | Part 1 | Part 2 | Runtime |"),
+            String::from("| ---: | --: | :---- | :----: | :----: | ------: |"),
+        ],
+        false => vec![
+            String::from("| Year | Day | Title | Part 1 | Part 2 |"),
+            String::from("| ---: | --: | :---- | :----: | :----: |"),
+        ],
+    };
+    let mut stars = 0;
+    for row in rows.iter() {
+        let cells: [&str; 2] = match &row.parts {
+            Some(parts) => parts.each_ref().map(|status| match status {
+                Status::Pass => "⭐",
+                Status::Fail { .. } | Status::Error { .. } => "❌",
+                Status::Missing => "–",
+                Status::NoInput => "no input",
+            }),
+            None => ["no input", "no input"],
+        };
+        stars += cells.iter().filter(|cell| **cell == "⭐").count();
+        let mut line = format!("| {} | {} | {} | {} | {} |", row.year, row.day, row.title, cells[0], cells[1]);
+        if runtimes {
+            match row.runtime {
+                Some(runtime) => line.push_str(&format!(" {:.2?} |", runtime)),
+                None => line.push_str(" – |"),
+            }
+        }
+        lines.push(line);
+    }
+    lines.push(String::new());
+    lines.push(format!("{} stars from {} days.", stars, rows.len()));
+    return lines.join("\n");
+}
+
+/// Returns the text with everything between the report markers replaced by `section`, or
+/// `None` if the text does not have both markers in order.
+///
+/// # Arguments
+///
+/// * `text` - the text of the README.
+/// * `section` - the generated Markdown to put between the markers.
+pub fn replace_section(text: &str, section: &str) -> Option<String> {
+    let start = text.find(START_MARKER)? + START_MARKER.len();
+    let end = start + text[start..].find(END_MARKER)?;
+    return Some(format!("{}\n{}\n{}", &text[..start], section.trim_end(), &text[end..]));
+}
+
+/// Rewrites the marked section of a README with the progress table.
+///
+/// # Arguments
+///
+/// * `path` - the path to the README.
+/// * `rows` - the progress on each day.
+/// * `runtimes` - whether to include the runtime column.
+pub fn write_readme(path: &Path, rows: &[Row], runtimes: bool) -> Result<()> {
+    let text = fs::read_to_string(path).map_err(|err| Error::io(path, err))?;
+    let Some(updated) = replace_section(&text, &format_table(rows, runtimes)) else {
+        let message = format!("expected a {} line followed by a {} line", START_MARKER, END_MARKER);
+        return Err(Error::io(path, io::Error::new(io::ErrorKind::InvalidData, message)));
+    };
+    return fs::write(path, updated).map_err(|err| Error::io(path, err));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::example_inputs;
+
+    fn row(day: u8, parts: Option<[Status; 2]>) -> Row {
+        return Row {
+            year: 2022,
+            day,
+            title: "Camp Cleanup",
+            runtime: parts.as_ref().map(|_| Duration::from_micros(1500)),
+            parts,
+        };
+    }
+
+    #[test]
+    fn test_format_table() {
+        let rows = vec![
+            row(4, Some([Status::Pass, Status::Missing])),
+            row(5, Some([Status::Fail { expected: String::from("1") }, Status::Pass])),
+            row(6, None),
+        ];
+        assert_eq!(
+            "| Year | Day | Title | Part 1 | Part 2 | Runtime |
+| ---: | --: | :---- | :----: | :----: | ------: |
+| 2022 | 4 | Camp Cleanup | ⭐ | – | 1.50ms |
+| 2022 | 5 | Camp Cleanup | ❌ | ⭐ | 1.50ms |
+| 2022 | 6 | Camp Cleanup | no input | no input | – |
+
+2 stars from 3 days.",
+            format_table(&rows, true)
+        );
+        assert_eq!(
+            "| Year | Day | Title | Part 1 | Part 2 |
+| ---: | --: | :---- | :----: | :----: |
+| 2022 | 4 | Camp Cleanup | ⭐ | – |
+| 2022 | 5 | Camp Cleanup | ❌ | ⭐ |
+| 2022 | 6 | Camp Cleanup | no input | no input |
+
+2 stars from 3 days.",
+            format_table(&rows, false)
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# Title\n\n<!-- report:start -->\nold table\n<!-- report:end -->\n\nMore text.\n";
+        assert_eq!(
+            "# Title\n\n<!-- report:start -->\nnew table\n<!-- report:end -->\n\nMore text.\n",
+            replace_section(readme, "new table\n").unwrap()
+        );
+        assert_eq!(None, replace_section("# Title\n", "new table"));
+        assert_eq!(None, replace_section("<!-- report:end -->\n<!-- report:start -->\n", "new table"));
+    }
+
+    #[test]
+    fn test_rows() {
+        let answers = Answers::from_text("[[answer]]\nyear = 2022\nday = 4\npart = 1\ninput = \"2022/4.txt\"\nanswer = \"2\"\n").unwrap();
+        let cache = example_inputs("report");
+        let rows = rows(&answers, &InputProvider::new(&cache)).unwrap();
+        let day_four = rows.iter().find(|row| (row.year, row.day) == (2022, 4)).unwrap();
+        assert_eq!(Some([Status::Pass, Status::Missing]), day_four.parts);
+        assert!(day_four.runtime.is_some());
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_rows_without_inputs() {
+        let cache = std::env::temp_dir().join(format!("advent-of-code-report-{}", std::process::id()));
+        let rows = rows(&Answers::default(), &InputProvider::new(cache)).unwrap();
+        assert_eq!(DAYS.len(), rows.len());
+        assert!(rows.iter().all(|row| row.parts.is_none() && row.runtime.is_none()));
+    }
+}
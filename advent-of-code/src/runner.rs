@@ -0,0 +1,518 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache::{input_hash, AnswerCache};
+use crate::error::Result;
+use crate::provider::InputProvider;
+use crate::registry::{days, find_day};
+use crate::solution::{Day, Solution};
+
+/// Returns the answer to a single part of a parsed puzzle.
+///
+/// # Arguments
+///
+/// * `solution` - the parsed puzzle.
+/// * `part` - the part of the puzzle to answer, either `1` or `2`.
+pub fn solve(solution: &dyn Solution, part: u8) -> String {
+    return match part {
+        1 => solution.part_one(),
+        _ => solution.part_two(),
+    };
+}
+
+/// How the runner prints its results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// A heading per day followed by one line per part.
+    #[default]
+    Plain,
+    /// A JSON array of `Record`s, or for a full run an object with each day's records and timing.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(text: &str) -> std::result::Result<Self, Self::Err> {
+        return match text {
+            "plain" => Ok(Self::Plain),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("unknown format {:?}, expected plain or json", text)),
+        };
+    }
+}
+
+/// The answer to one part of a day's puzzle and the time taken to compute it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// The time taken to answer the part, not including parsing the input. Zero for cached answers.
+    pub duration_ns: u64,
+    /// Whether the answer came from the answer cache instead of being solved.
+    pub cached: bool,
+}
+
+/// Why a day of a full run has no records. In JSON, `{"reason": "no_input"}` or
+/// `{"reason": "error", "message": "..."}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "reason", content = "message", rename_all = "snake_case")]
+pub enum Skipped {
+    /// The puzzle input is not cached and could not be downloaded.
+    NoInput,
+    /// The puzzle input could not be read or parsed.
+    Error(String),
+}
+
+/// The records for one day of a full run, and the time taken to load, parse and solve it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayRun {
+    pub year: u16,
+    pub day: u8,
+    pub records: Vec<Record>,
+    pub duration: Duration,
+    /// Why the day was not solved, or `None` if it was.
+    pub skipped: Option<Skipped>,
+}
+
+/// A day of a full run as printed in JSON.
+#[derive(Serialize)]
+struct JsonRun<'a> {
+    year: u16,
+    day: u8,
+    duration_ns: u64,
+    skipped: Option<&'a Skipped>,
+    records: &'a [Record],
+}
+
+/// A full run as printed in JSON.
+#[derive(Serialize)]
+struct JsonRuns<'a> {
+    days: Vec<JsonRun<'a>>,
+    /// The wall-clock time of the whole run, which is less than the sum of the days' durations
+    /// when several days run at once.
+    total_ns: u64,
+    jobs: usize,
+}
+
+/// Returns the records for the requested parts of a day's puzzle.
+///
+/// # Arguments
+///
+/// * `day` - the registered day to run.
+/// * `text` - the full text of the puzzle input.
+/// * `part` - the single part to run, or `None` to run both.
+pub fn solve_day(day: &Day, text: &str, part: Option<u8>) -> Result<Vec<Record>> {
+    return solve_day_with_cache(day, text, part, None);
+}
+
+/// Returns the records for the requested parts of a day's puzzle, taking answers from the cache
+/// when it has them. The input is only parsed if some part has to be solved, and the answers
+/// that are solved are added to the cache.
+///
+/// # Arguments
+///
+/// * `day` - the registered day to run.
+/// * `text` - the full text of the puzzle input.
+/// * `part` - the single part to run, or `None` to run both.
+/// * `cache` - the answer cache, or `None` to always solve.
+pub fn solve_day_with_cache(day: &Day, text: &str, part: Option<u8>, cache: Option<&AnswerCache>) -> Result<Vec<Record>> {
+    let input = cache.map(|_| input_hash(text));
+    let mut solution: Option<Box<dyn Solution>> = None;
+    let mut records: Vec<Record> = Vec::new();
+    for current in parts(part) {
+        let cached = cache
+            .zip(input.as_deref())
+            .and_then(|(cache, input)| cache.get(day.year, day.number, current, input));
+        if let Some(answer) = cached {
+            records.push(Record {
+                year: day.year,
+                day: day.number,
+                part: current,
+                answer,
+                duration_ns: 0,
+                cached: true,
+            });
+            continue;
+        }
+
+        let solution = match solution {
+            Some(ref solution) => solution,
+            None => solution.insert(day.parse(text)?),
+        };
+        let start = Instant::now();
+        let answer = solve(solution.as_ref(), current);
+        let duration_ns = start.elapsed().as_nanos() as u64;
+        if let Some((cache, input)) = cache.zip(input.as_deref()) {
+            cache.insert(day.year, day.number, current, input, &answer);
+        }
+        records.push(Record {
+            year: day.year,
+            day: day.number,
+            part: current,
+            answer,
+            duration_ns,
+            cached: false,
+        });
+    }
+    return Ok(records);
+}
+
+/// Parses the input for a day and prints the answers for the requested parts.
+///
+/// # Arguments
+///
+/// * `day` - the registered day to run.
+/// * `text` - the full text of the puzzle input.
+/// * `part` - the single part to run, or `None` to run both.
+/// * `format` - how to print the answers.
+pub fn run_day(day: &Day, text: &str, part: Option<u8>, format: Format) -> Result<()> {
+    let records = solve_day(day, text, part)?;
+    println!("{}", format_records(&records, format));
+    return Ok(());
+}
+
+/// Returns the runs for several days, loading, parsing and solving them on a pool of worker
+/// threads. The runs are in the same order as `days`, whichever finishes first. A day whose
+/// input is missing or cannot be parsed is skipped, and the other days still run.
+///
+/// # Arguments
+///
+/// * `inputs` - where to find each day's puzzle input.
+/// * `days` - the registered days to run.
+/// * `part` - the single part to run, or `None` to run both.
+/// * `jobs` - the number of worker threads, at least 1.
+/// * `cache` - the answer cache, or `None` to always solve.
+pub fn solve_days(
+    inputs: &InputProvider,
+    days: &[&Day],
+    part: Option<u8>,
+    jobs: usize,
+    cache: Option<&AnswerCache>,
+) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<DayRun>>> = Mutex::new(days.iter().map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = days.get(index) else {
+                    break;
+                };
+                let start = Instant::now();
+                let result = inputs
+                    .get(day.year, day.number)
+                    .and_then(|text| solve_day_with_cache(day, &text, part, cache));
+                let (records, skipped) = match result {
+                    Ok(records) => (records, None),
+                    Err(err) if err.is_not_found() => (Vec::new(), Some(Skipped::NoInput)),
+                    Err(err) => (Vec::new(), Some(Skipped::Error(err.to_string()))),
+                };
+                let run = DayRun {
+                    year: day.year,
+                    day: day.number,
+                    records,
+                    duration: start.elapsed(),
+                    skipped,
+                };
+                results.lock().unwrap()[index] = Some(run);
+            });
+        }
+    });
+    return results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every day is taken by a worker"))
+        .collect();
+}
+
+/// Runs every registered day of an event, or of every event, against its puzzle input, and
+/// prints the answers in day order followed by the total time taken and the number of answers
+/// that came from the cache. Returns the number of days whose input could not be parsed; days
+/// without an input are only reported.
+///
+/// # Arguments
+///
+/// * `inputs` - where to find each day's puzzle input.
+/// * `year` - the event to run, or `None` to run every event.
+/// * `part` - the single part to run, or `None` to run both.
+/// * `format` - how to print the answers.
+/// * `jobs` - the number of days to run at once.
+/// * `cache` - the answer cache, or `None` to always solve.
+pub fn run_all(
+    inputs: &InputProvider,
+    year: Option<u16>,
+    part: Option<u8>,
+    format: Format,
+    jobs: usize,
+    cache: Option<&AnswerCache>,
+) -> usize {
+    let start = Instant::now();
+    let runs = solve_days(inputs, &days(year).collect::<Vec<&Day>>(), part, jobs, cache);
+    println!("{}", format_runs(&runs, start.elapsed(), jobs, format));
+    return runs.iter().filter(|run| matches!(run.skipped, Some(Skipped::Error(_)))).count();
+}
+
+/// Returns the runs formatted for printing, followed by the total time taken. Plain text shows
+/// the time taken by each day next to its heading, or why it was skipped, and ends with the
+/// number of answers that came from the cache. JSON is an object with a `days` array, holding
+/// each day's `duration_ns`, `skipped` reason and `Record`s, alongside `total_ns` and `jobs`.
+///
+/// # Arguments
+///
+/// * `runs` - the runs to format, in year and then day order.
+/// * `total` - the wall-clock time taken by the whole run.
+/// * `jobs` - the number of days that were run at once.
+/// * `format` - how to format the runs.
+pub fn format_runs(runs: &[DayRun], total: Duration, jobs: usize, format: Format) -> String {
+    if format == Format::Json {
+        let json = JsonRuns {
+            days: runs
+                .iter()
+                .map(|run| JsonRun {
+                    year: run.year,
+                    day: run.day,
+                    duration_ns: run.duration.as_nanos() as u64,
+                    skipped: run.skipped.as_ref(),
+                    records: &run.records,
+                })
+                .collect(),
+            total_ns: total.as_nanos() as u64,
+            jobs,
+        };
+        return serde_json::to_string_pretty(&json).expect("runs always serialize to JSON");
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    for run in runs.iter() {
+        match &run.skipped {
+            Some(Skipped::NoInput) => lines.push(format!("{} (no input)", heading(run.year, run.day))),
+            Some(Skipped::Error(message)) => lines.push(format!("{} (error: {})", heading(run.year, run.day), message)),
+            None => lines.push(format!("{} ({:.2?})", heading(run.year, run.day), run.duration)),
+        }
+        for record in run.records.iter() {
+            let cached = if record.cached { " (cached)" } else { "" };
+            lines.push(format!("Part {}: {}{}", record.part, record.answer, cached));
+        }
+    }
+    let records: Vec<&Record> = runs.iter().flat_map(|run| run.records.iter()).collect();
+    let hits = records.iter().filter(|record| record.cached).count();
+    lines.push(format!("Total: {:.2?} with {} jobs, {} of {} answers cached", total, jobs, hits, records.len()));
+    return lines.join("\n");
+}
+
+/// Returns the records formatted for printing.
+///
+/// # Arguments
+///
+/// * `records` - the records to format, in year and then day order.
+/// * `format` - how to format the records.
+pub fn format_records(records: &[Record], format: Format) -> String {
+    if format == Format::Json {
+        return serde_json::to_string_pretty(records).expect("records always serialize to JSON");
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut current_day: Option<(u16, u8)> = None;
+    for record in records.iter() {
+        if current_day != Some((record.year, record.day)) {
+            current_day = Some((record.year, record.day));
+            lines.push(heading(record.year, record.day));
+        }
+        lines.push(format!("Part {}: {}", record.part, record.answer));
+    }
+    return lines.join("\n");
+}
+
+/// Prints every registered day and where its puzzle input is cached.
+///
+/// # Arguments
+///
+/// * `inputs` - where to find each day's puzzle input.
+pub fn list(inputs: &InputProvider) {
+    for day in days(None) {
+        println!(
+            "{} Day {:>2}: {:<28} {}",
+            day.year,
+            day.number,
+            day.title,
+            inputs.path(day.year, day.number).display()
+        );
+    }
+}
+
+fn heading(year: u16, number: u8) -> String {
+    return match find_day(year, number) {
+        Some(day) => format!("{} Day {}: {}", day.year, day.number, day.title),
+        None => format!("{} Day {}", year, number),
+    };
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    return match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::example_inputs;
+
+    const EXAMPLE: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n";
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(vec![1, 2], parts(None));
+        assert_eq!(vec![2], parts(Some(2)));
+    }
+
+    #[test]
+    fn test_solve() {
+        let day = find_day(2022, 4).unwrap();
+        let solution = day.parse(EXAMPLE).unwrap();
+        assert_eq!("2", solve(solution.as_ref(), 1));
+        assert_eq!("4", solve(solution.as_ref(), 2));
+    }
+
+    #[test]
+    fn test_solve_day() {
+        let records = solve_day(find_day(2022, 4).unwrap(), EXAMPLE, Some(2)).unwrap();
+        assert_eq!(1, records.len());
+        assert_eq!((4, 2, "4"), (records[0].day, records[0].part, records[0].answer.as_str()));
+    }
+
+    #[test]
+    fn test_solve_days_keeps_day_order() {
+        let cache = example_inputs("runner");
+        let inputs = InputProvider::new(&cache);
+        let mut days: Vec<&Day> = days(Some(2022)).collect();
+        days.reverse();
+        let runs = solve_days(&inputs, &days, None, 3, None);
+        let order: Vec<u8> = runs.iter().map(|run| run.day).collect();
+        assert_eq!(days.iter().map(|day| day.number).collect::<Vec<u8>>(), order);
+        // Newly scaffolded days only have a placeholder input, so they are skipped.
+        for run in runs.iter().filter(|run| run.skipped.is_none()) {
+            let sequential = solve_day(find_day(run.year, run.day).unwrap(), &inputs.get(run.year, run.day).unwrap(), None);
+            let answers = |records: &[Record]| records.iter().map(|record| record.answer.clone()).collect::<Vec<String>>();
+            assert_eq!(answers(&sequential.unwrap()), answers(&run.records));
+        }
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_solve_days_skips_bad_days() {
+        let cache = std::env::temp_dir().join(format!("advent-of-code-runner-{}", std::process::id()));
+        std::fs::create_dir_all(cache.join("2022")).unwrap();
+        std::fs::write(cache.join("2022/4.txt"), EXAMPLE).unwrap();
+        std::fs::write(cache.join("2022/24.txt"), "2-4\n").unwrap();
+        let inputs = InputProvider::new(&cache);
+        let missing = Day::new::<crate::y2022::day_4::Sections>(2022, 25, "Missing");
+        let broken = Day::new::<crate::y2022::day_4::Sections>(2022, 24, "Broken");
+        let days = [&missing, &broken, find_day(2022, 4).unwrap()];
+
+        let runs = solve_days(&inputs, &days, None, 2, None);
+        assert_eq!(Some(Skipped::NoInput), runs[0].skipped);
+        assert!(matches!(&runs[1].skipped, Some(Skipped::Error(message)) if message.contains("line 1")));
+        assert_eq!((None, 2), (runs[2].skipped.clone(), runs[2].records.len()));
+        assert_eq!(
+            "2022 Day 25 (no input)\nTotal: 1.00ms with 2 jobs, 0 of 0 answers cached",
+            format_runs(&runs[..1], Duration::from_millis(1), 2, Format::Plain)
+        );
+        let json: serde_json::Value = serde_json::from_str(&format_runs(&runs, Duration::from_millis(1), 2, Format::Json)).unwrap();
+        assert_eq!(serde_json::json!({"reason": "no_input"}), json["days"][0]["skipped"]);
+        assert_eq!("error", json["days"][1]["skipped"]["reason"]);
+        assert_eq!(serde_json::Value::Null, json["days"][2]["skipped"]);
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_solve_day_with_cache() {
+        let cache = AnswerCache::new();
+        let day = find_day(2022, 4).unwrap();
+        let first = solve_day_with_cache(day, EXAMPLE, None, Some(&cache)).unwrap();
+        assert!(first.iter().all(|record| !record.cached));
+        assert_eq!(2, cache.len());
+
+        let second = solve_day_with_cache(day, EXAMPLE, None, Some(&cache)).unwrap();
+        assert!(second.iter().all(|record| record.cached && record.duration_ns == 0));
+        assert_eq!(
+            first.iter().map(|record| &record.answer).collect::<Vec<&String>>(),
+            second.iter().map(|record| &record.answer).collect::<Vec<&String>>()
+        );
+
+        // A cached answer is trusted without parsing, so a changed input is what invalidates it.
+        let changed = solve_day_with_cache(day, "2-4,6-8\n", Some(1), Some(&cache)).unwrap();
+        assert_eq!((false, "0"), (changed[0].cached, changed[0].answer.as_str()));
+    }
+
+    #[test]
+    fn test_solve_day_with_cache_skips_parsing() {
+        let cache = AnswerCache::new();
+        let day = find_day(2022, 4).unwrap();
+        cache.insert(2022, 4, 1, &input_hash("not an input"), "7");
+        let records = solve_day_with_cache(day, "not an input", Some(1), Some(&cache)).unwrap();
+        assert_eq!("7", records[0].answer);
+        assert!(solve_day_with_cache(day, "not an input", None, Some(&cache)).is_err());
+    }
+
+    #[test]
+    fn test_format_runs() {
+        let runs = vec![DayRun {
+            year: 2022,
+            day: 4,
+            records: solve_day(find_day(2022, 4).unwrap(), EXAMPLE, None).unwrap(),
+            duration: Duration::from_micros(1500),
+            skipped: None,
+        }];
+        assert_eq!(
+            "2022 Day 4: Camp Cleanup (1.50ms)\nPart 1: 2\nPart 2: 4\nTotal: 2.00ms with 1 jobs, 0 of 2 answers cached",
+            format_runs(&runs, Duration::from_millis(2), 1, Format::Plain)
+        );
+        let json: serde_json::Value = serde_json::from_str(&format_runs(&runs, Duration::from_millis(2), 1, Format::Json)).unwrap();
+        assert_eq!(2_000_000, json["total_ns"]);
+        assert_eq!(1, json["jobs"]);
+        assert_eq!(1_500_000, json["days"][0]["duration_ns"]);
+        assert_eq!(2, json["days"][0]["records"].as_array().unwrap().len());
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!(Ok(Format::Json), "json".parse::<Format>());
+        assert_eq!(Ok(Format::Plain), "plain".parse::<Format>());
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_format_plain() {
+        let records = solve_day(find_day(2022, 4).unwrap(), EXAMPLE, None).unwrap();
+        assert_eq!("2022 Day 4: Camp Cleanup\nPart 1: 2\nPart 2: 4", format_records(&records, Format::Plain));
+    }
+
+    #[test]
+    fn test_format_json() {
+        let records = vec![Record {
+            year: 2022,
+            day: 4,
+            part: 1,
+            answer: String::from("2"),
+            duration_ns: 1500,
+            cached: false,
+        }];
+        let json: serde_json::Value = serde_json::from_str(&format_records(&records, Format::Json)).unwrap();
+        assert_eq!(
+            serde_json::json!([{"year": 2022, "day": 4, "part": 1, "answer": "2", "duration_ns": 1500, "cached": false}]),
+            json
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use crate::provider::InputProvider;
+
+/// The module created for a new day, modelled on the existing days.
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// The files created for a new day, relative to the crate directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewDay {
+    /// The name of the `Solution` type in the new module.
+    pub type_name: String,
+    pub files: Vec<PathBuf>,
+}
+
+/// Creates the module and example files for a new day, registers it with its year's module and
+/// the registry, and leaves an empty placeholder for its input unless the input is already cached.
+/// The year's module is created too when this is the first day of a new event.
+///
+/// # Arguments
+///
+/// * `root` - the crate directory.
+/// * `inputs` - the directory of cached puzzle inputs, relative to `root` unless it is absolute.
+/// * `year` - the year of the event.
+/// * `number` - the day of the event.
+/// * `title` - the title of the puzzle, also used to name the `Solution` type.
+pub fn new_day(root: &Path, inputs: &Path, year: u16, number: u8, title: &str) -> Result<NewDay> {
+    let type_name = type_name(title);
+    if type_name.is_empty() || !type_name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err(Error::parse(title, "expected the title to start with a letter"));
+    }
+    let year_module = format!("y{}", year);
+    let module = format!("{}/day_{}", year_module, number);
+    let mut files = vec![
+        PathBuf::from(format!("src/{}/mod.rs", module)),
+        PathBuf::from(format!("src/{}/examples/example.txt", module)),
+        PathBuf::from(format!("src/{}/examples/example.toml", module)),
+    ];
+    let contents = [
+        TEMPLATE.replace("{{TYPE}}", &type_name),
+        String::new(),
+        String::from("# Expected answers for example.txt, fill these in from the puzzle text.\n# part_one = \"\"\n# part_two = \"\"\n"),
+    ];
+
+    fs::create_dir_all(root.join("src").join(&module).join("examples")).map_err(|err| Error::io(root, err))?;
+    for (file, content) in files.iter().zip(contents.iter()) {
+        create_new(&root.join(file), content)?;
+    }
+
+    let input = inputs.join(InputProvider::relative_path(year, number));
+    if !root.join(&input).exists() {
+        let directory = root.join(input.parent().unwrap());
+        fs::create_dir_all(&directory).map_err(|err| Error::io(&directory, err))?;
+        create_new(&root.join(&input), "")?;
+        files.push(input);
+    }
+
+    let year_file = PathBuf::from(format!("src/{}/mod.rs", year_module));
+    let module_line = format!("pub mod day_{};", number);
+    if root.join(&year_file).exists() {
+        insert_line(&root.join(&year_file), "pub mod day_", &[number as u32], &module_line)?;
+    } else {
+        let header = format!("//! Solutions to the puzzles of Advent of Code {}.\n\n{}\n", year, module_line);
+        create_new(&root.join(&year_file), &header)?;
+        files.push(year_file);
+        insert_line(
+            &root.join("src/lib.rs"),
+            "pub mod y",
+            &[year as u32],
+            &format!("pub mod {};", year_module),
+        )?;
+    }
+
+    let registry = root.join("src/registry.rs");
+    let key = [year as u32, number as u32];
+    insert_line(
+        &registry,
+        "use crate::y",
+        &key,
+        &format!("use crate::{}::{};", module.replace('/', "::"), type_name),
+    )?;
+    insert_line(
+        &registry,
+        "    Day::new::<",
+        &key,
+        &format!("    Day::new::<{}>({}, {}, {:?}),", type_name, year, number, title),
+    )?;
+
+    return Ok(NewDay { type_name, files });
+}
+
+/// Returns the title as an UpperCamelCase type name, such as `SupplyStacks` for "Supply Stacks".
+///
+/// # Arguments
+///
+/// * `title` - the title of the puzzle.
+pub fn type_name(title: &str) -> String {
+    let mut name = String::new();
+    for word in title.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+    return name;
+}
+
+/// Writes a file that must not already exist.
+fn create_new(path: &Path, content: &str) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|err| Error::io(path, err))?;
+    file.write_all(content.as_bytes()).map_err(|err| Error::io(path, err))?;
+    return Ok(());
+}
+
+/// Inserts a line into a run of lines that each start with `prefix` followed by a year or day
+/// number, keeping the run in order.
+///
+/// # Arguments
+///
+/// * `path` - the file to edit.
+/// * `prefix` - the text before the numbers on each line of the run.
+/// * `key` - the numbers of the new line, such as its year and day.
+/// * `line` - the line to insert.
+fn insert_line(path: &Path, prefix: &str, key: &[u32], line: &str) -> Result<()> {
+    let text = fs::read_to_string(path).map_err(|err| Error::io(path, err))?;
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let mut position: Option<usize> = None;
+    for (index, existing) in lines.iter().enumerate() {
+        let Some(rest) = existing.strip_prefix(prefix) else {
+            continue;
+        };
+        position = Some(index + 1);
+        if sort_key(rest).as_slice() > key {
+            position = Some(index);
+            break;
+        }
+    }
+    let Some(position) = position else {
+        return Err(Error::parse(&path.display().to_string(), &format!("expected a line starting with {:?}", prefix)));
+    };
+
+    lines.insert(position, line);
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    fs::write(path, updated).map_err(|err| Error::io(path, err))?;
+    return Ok(());
+}
+
+/// Returns the year and day numbers of a registration line, after the prefix. Numbers are read
+/// from inside the brackets of a `Day::new` call, and stop at the type name or the title so that
+/// digits in either are ignored.
+fn sort_key(rest: &str) -> Vec<u32> {
+    let rest = match rest.find('(') {
+        Some(open) => &rest[open + 1..],
+        None => rest,
+    };
+    let end = rest.find(|c: char| c.is_ascii_uppercase() || c == '"').unwrap_or(rest.len());
+    return rest[..end]
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|digits| digits.parse().ok())
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const LIB: &str = "pub mod error;\npub mod verify;\npub mod y2022;\n";
+    const YEAR: &str = "//! Solutions to the puzzles of Advent of Code 2022.\n\npub mod day_1;\npub mod day_4;\n";
+    const REGISTRY: &str = "use crate::solution::Day;
+use crate::y2022::day_1::Elves;
+use crate::y2022::day_4::Sections;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<Elves>(2022, 1, \"Calorie Counting\"),
+    Day::new::<Sections>(2022, 4, \"Camp Cleanup\"),
+];
+";
+
+    fn fake_crate(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("advent-of-code-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/y2022")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/y2022/mod.rs"), YEAR).unwrap();
+        fs::write(root.join("src/registry.rs"), REGISTRY).unwrap();
+        return root;
+    }
+
+    #[test]
+    fn test_type_name() {
+        assert_eq!("SupplyStacks", type_name("Supply Stacks"));
+        assert_eq!("NoSpaceLeftOnDevice", type_name("No Space Left On Device"));
+        assert_eq!("TuningTrouble", type_name("tuning-trouble"));
+    }
+
+    #[test]
+    fn test_sort_key() {
+        assert_eq!(vec![12], sort_key("12;"));
+        assert_eq!(vec![2022, 3], sort_key("2022::day_3::Rucksacks;"));
+        assert_eq!(vec![2022, 5], sort_key("Day5>(2022, 5, \"Day 5\"),"));
+        assert!(sort_key("error;").is_empty());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = fake_crate("new-day");
+        let new_day = new_day(&root, Path::new("inputs"), 2022, 2, "Rock Paper Scissors").unwrap();
+        assert_eq!("RockPaperScissors", new_day.type_name);
+        for file in new_day.files.iter() {
+            assert!(root.join(file).is_file(), "{}", file.display());
+        }
+        assert_eq!(4, new_day.files.len());
+        assert_eq!("", fs::read_to_string(root.join("inputs/2022/2.txt")).unwrap());
+        let module = fs::read_to_string(root.join("src/y2022/day_2/mod.rs")).unwrap();
+        assert!(module.contains("impl Solution for RockPaperScissors {"));
+
+        assert_eq!(LIB, fs::read_to_string(root.join("src/lib.rs")).unwrap());
+        assert!(fs::read_to_string(root.join("src/y2022/mod.rs"))
+            .unwrap()
+            .ends_with("pub mod day_1;\npub mod day_2;\npub mod day_4;\n"));
+        let registry = fs::read_to_string(root.join("src/registry.rs")).unwrap();
+        assert!(registry.contains(
+            "use crate::y2022::day_1::Elves;\nuse crate::y2022::day_2::RockPaperScissors;\nuse crate::y2022::day_4::Sections;\n"
+        ));
+        assert!(registry.contains(
+            "(2022, 1, \"Calorie Counting\"),\n    Day::new::<RockPaperScissors>(2022, 2, \"Rock Paper Scissors\"),\n"
+        ));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_appends_last() {
+        let root = fake_crate("append");
+        new_day(&root, Path::new("inputs"), 2022, 5, "Supply Stacks").unwrap();
+        let registry = fs::read_to_string(root.join("src/registry.rs")).unwrap();
+        assert!(registry.contains(
+            "(2022, 4, \"Camp Cleanup\"),\n    Day::new::<SupplyStacks>(2022, 5, \"Supply Stacks\"),\n];"
+        ));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_new_year() {
+        let root = fake_crate("new-year");
+        let new_day = new_day(&root, Path::new("inputs"), 2023, 1, "Trebuchet").unwrap();
+        assert!(new_day.files.contains(&PathBuf::from("src/y2023/mod.rs")));
+        assert_eq!(
+            "//! Solutions to the puzzles of Advent of Code 2023.\n\npub mod day_1;\n",
+            fs::read_to_string(root.join("src/y2023/mod.rs")).unwrap()
+        );
+        assert_eq!(
+            "pub mod error;\npub mod verify;\npub mod y2022;\npub mod y2023;\n",
+            fs::read_to_string(root.join("src/lib.rs")).unwrap()
+        );
+        let registry = fs::read_to_string(root.join("src/registry.rs")).unwrap();
+        assert!(registry.contains("use crate::y2022::day_4::Sections;\nuse crate::y2023::day_1::Trebuchet;\n"));
+        assert!(registry.contains("(2022, 4, \"Camp Cleanup\"),\n    Day::new::<Trebuchet>(2023, 1, \"Trebuchet\"),\n];"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_keeps_cached_input() {
+        let root = fake_crate("cached");
+        fs::create_dir_all(root.join("inputs/2022")).unwrap();
+        fs::write(root.join("inputs/2022/6.txt"), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap();
+        let new_day = new_day(&root, Path::new("inputs"), 2022, 6, "Tuning Trouble").unwrap();
+        assert_eq!(3, new_day.files.len());
+        assert_eq!("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n", fs::read_to_string(root.join("inputs/2022/6.txt")).unwrap());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_already_exists() {
+        let root = fake_crate("exists");
+        fs::create_dir_all(root.join("src/y2022/day_4")).unwrap();
+        fs::write(root.join("src/y2022/day_4/mod.rs"), "").unwrap();
+        assert!(matches!(new_day(&root, Path::new("inputs"), 2022, 4, "Camp Cleanup"), Err(Error::Io { .. })));
+        assert_eq!(YEAR, fs::read_to_string(root.join("src/y2022/mod.rs")).unwrap());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::Result;
+
+/// The answer a newly scaffolded day gives for both parts until they are solved.
+pub const UNSOLVED: &str = "unsolved";
+
+/// The shared shape of every day's puzzle: parse the input once, then answer both parts.
+pub trait Solution {
+    /// Returns the parsed puzzle input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The full text of the puzzle input.
+    fn parse(input: &str) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Returns the answer to the first part of the puzzle.
+    fn part_one(&self) -> String;
+
+    /// Returns the answer to the second part of the puzzle.
+    fn part_two(&self) -> String;
+}
+
+/// A single registered day, mapping a year and day number to its `Solution` implementation.
+pub struct Day {
+    /// The year of the event this puzzle belongs to.
+    pub year: u16,
+    /// The day of the event this puzzle belongs to.
+    pub number: u8,
+    /// The title of the puzzle.
+    pub title: &'static str,
+    /// Parses the input into the day's `Solution` implementation.
+    parse: fn(&str) -> Result<Box<dyn Solution>>,
+}
+
+impl Day {
+    /// Returns a registry entry for the `Solution` implementation `S`.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - the year of the event.
+    /// * `number` - the day of the event.
+    /// * `title` - the title of the puzzle.
+    pub const fn new<S: Solution + 'static>(year: u16, number: u8, title: &'static str) -> Self {
+        Self {
+            year,
+            number,
+            title,
+            parse: parse_boxed::<S>,
+        }
+    }
+
+    /// Returns the parsed puzzle input for this day.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The full text of the puzzle input.
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Solution>> {
+        return (self.parse)(input);
+    }
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solution>> {
+    return Ok(Box::new(S::parse(input)?));
+}
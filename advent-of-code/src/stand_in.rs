@@ -0,0 +1,100 @@
+//! Local stand-ins for the Advent of Code website and the puzzle inputs downloaded from it, for
+//! testing code that talks to one or reads the other.
+
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use crate::provider::InputProvider;
+use crate::registry::DAYS;
+
+/// A request received by the stand-in server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// The request line, such as `GET /2022/day/1/input HTTP/1.1`.
+    pub line: String,
+    pub headers: Vec<String>,
+    pub body: String,
+}
+
+impl Request {
+    /// Returns true if the request had the header, ignoring case.
+    pub fn has_header(&self, header: &str) -> bool {
+        return self.headers.iter().any(|existing| existing.eq_ignore_ascii_case(header));
+    }
+}
+
+/// Serves a single HTTP request with the given status and body, returning the base URL to
+/// send the request to and a handle that yields the request once it has been answered.
+///
+/// # Arguments
+///
+/// * `status` - the status line of the response, such as `200 OK`.
+/// * `body` - the body of the response.
+pub fn serve_once(status: &'static str, body: &'static str) -> (String, thread::JoinHandle<Request>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let mut request = Request {
+            line: String::from(line.trim()),
+            headers: Vec::new(),
+            body: String::new(),
+        };
+        let mut length = 0;
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).unwrap();
+            let header = header.trim();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    length = value.trim().parse().unwrap();
+                }
+            }
+            request.headers.push(String::from(header));
+        }
+        let mut content = vec![0; length];
+        reader.read_exact(&mut content).unwrap();
+        request.body = String::from_utf8(content).unwrap();
+
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).unwrap();
+        return request;
+    });
+    return (base_url, handle);
+}
+
+/// Returns a fresh inputs directory holding every registered day's `example.txt` as its puzzle
+/// input, since real inputs are personal and not committed. Days without an example are left out.
+///
+/// # Arguments
+///
+/// * `name` - a name for the directory, unique to the calling test.
+pub fn example_inputs(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("advent-of-code-examples-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    for day in DAYS.iter() {
+        let example = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/y{}/day_{}/examples/example.txt", day.year, day.number));
+        let Ok(text) = fs::read_to_string(example) else {
+            continue;
+        };
+        let path = dir.join(InputProvider::relative_path(day.year, day.number));
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, text).unwrap();
+    }
+    return dir;
+}
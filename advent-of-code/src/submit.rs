@@ -0,0 +1,560 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answers::toml_error;
+use crate::error::{Error, Result};
+use crate::provider::DEFAULT_BASE_URL;
+use crate::solution::UNSOLVED;
+
+/// What the Advent of Code website made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    /// The answer was accepted.
+    Correct,
+    /// The answer was wrong and higher than the right answer.
+    TooHigh,
+    /// The answer was wrong and lower than the right answer.
+    TooLow,
+    /// The answer was wrong, with no hint as to why.
+    Incorrect,
+    /// The answer was not checked because the last one was submitted too recently.
+    TooSoon,
+    /// The answer was not checked because the part is already solved or not yet unlocked.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Returns true if the answer was checked and found to be wrong.
+    pub fn is_rejection(&self) -> bool {
+        return matches!(self, Self::TooHigh | Self::TooLow | Self::Incorrect);
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return f.write_str(match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::Incorrect => "incorrect",
+            Self::TooSoon => "submitted too soon",
+            Self::WrongLevel => "already solved or not unlocked",
+        });
+    }
+}
+
+/// The website's response to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// How many seconds to wait before submitting another answer, if the website said.
+    pub wait: Option<u64>,
+}
+
+impl Response {
+    /// Returns the response described by the HTML page the website answers a submission with.
+    ///
+    /// # Arguments
+    ///
+    /// * `html` - the body of the response.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::submit::{Response, Verdict};
+    ///
+    /// let html = "<article><p>That's not the right answer; your answer is too high. \
+    ///             Please wait one minute before trying again.</p></article>";
+    /// let response = Response::parse(html)?;
+    /// assert_eq!(Verdict::TooHigh, response.verdict);
+    /// assert_eq!(Some(60), response.wait);
+    /// # Ok::<(), advent_of_code::Error>(())
+    /// ```
+    pub fn parse(html: &str) -> Result<Self> {
+        let text = html.to_lowercase();
+        let verdict = if text.contains("that's the right answer") {
+            Verdict::Correct
+        } else if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if text.contains("that's not the right answer") {
+            Verdict::Incorrect
+        } else if text.contains("you gave an answer too recently") {
+            Verdict::TooSoon
+        } else if text.contains("solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            let summary: String = html.chars().take(80).collect();
+            return Err(Error::parse(&summary, "expected the website to say whether the answer was right"));
+        };
+        return Ok(Self {
+            verdict,
+            wait: parse_wait(&text),
+        });
+    }
+}
+
+/// Returns the number of seconds the website asks to wait, from either "you have 1m 23s left
+/// to wait" or "please wait 5 minutes before trying again".
+fn parse_wait(text: &str) -> Option<u64> {
+    if let Some(end) = text.find(" left to wait") {
+        let start = text[..end].rfind("you have ")? + "you have ".len();
+        let mut seconds = 0;
+        for amount in text[start..end].split_whitespace() {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            seconds += match unit {
+                "h" => number * 3600,
+                "m" => number * 60,
+                "s" => number,
+                _ => return None,
+            };
+        }
+        return Some(seconds);
+    }
+
+    let start = text.find("please wait ")? + "please wait ".len();
+    let mut words = text[start..].split_whitespace();
+    let number = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    return match words.next()? {
+        "minute" | "minutes" => Some(number * 60),
+        "second" | "seconds" => Some(number),
+        _ => None,
+    };
+}
+
+/// Somewhere answers can be submitted to.
+pub trait Submitter: Send + Sync {
+    /// Returns the response to submitting an answer.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - the year of the event.
+    /// * `day` - the day of the event.
+    /// * `part` - the part of the puzzle.
+    /// * `answer` - the answer to submit.
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Response>;
+}
+
+/// Submits answers to the Advent of Code website, or anything serving the same paths.
+pub struct HttpSubmitter {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpSubmitter {
+    /// Returns a submitter for the Advent of Code website.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - the value of the `session` cookie of a logged in user.
+    pub fn new(session: &str) -> Self {
+        return Self::with_base_url(DEFAULT_BASE_URL, session);
+    }
+
+    /// Returns a submitter for a server other than the Advent of Code website.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - the URL that `/<year>/day/<day>/answer` is relative to.
+    /// * `session` - the value of the `session` cookie of a logged in user.
+    pub fn with_base_url(base_url: &str, session: &str) -> Self {
+        return Self {
+            base_url: String::from(base_url.trim_end_matches('/')),
+            session: String::from(session.trim()),
+            agent: ureq::Agent::new_with_defaults(),
+        };
+    }
+}
+
+impl Submitter for HttpSubmitter {
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Response> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let http_error = |err: ureq::Error| Error::Http {
+            url: url.clone(),
+            message: err.to_string(),
+        };
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", "github.com/JHowell45/advent-of-code-rust")
+            .send_form([("level", part.to_string()), ("answer", String::from(answer))])
+            .map_err(http_error)?;
+        let html = response.body_mut().read_to_string().map_err(http_error)?;
+        return Response::parse(&html);
+    }
+}
+
+/// An answer that was checked by the website.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Why an answer was not submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The answer is empty or the placeholder of a part that has not been solved.
+    Unsolved,
+    /// The part has already been solved.
+    AlreadySolved { answer: String },
+    /// The same answer has already been rejected.
+    AlreadyRejected { verdict: Verdict },
+    /// A rejected answer shows this one cannot be right either.
+    OutOfRange { rejected: String, verdict: Verdict },
+    /// The website asked to wait before submitting again.
+    TooSoon { seconds: u64 },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Self::Unsolved => write!(f, "the part has not been solved yet"),
+            Self::AlreadySolved { answer } => write!(f, "already solved with {}", answer),
+            Self::AlreadyRejected { verdict } => write!(f, "already rejected as {}", verdict),
+            Self::OutOfRange { rejected, verdict } => write!(f, "{} was rejected as {}", rejected, verdict),
+            Self::TooSoon { seconds } => write!(f, "wait {}s before submitting again", seconds),
+        };
+    }
+}
+
+/// The log of submitted answers, used to avoid submitting answers that are known to be wrong.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Submissions {
+    /// The time, in seconds since the Unix epoch, before which nothing should be submitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_until: Option<u64>,
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Returns the log loaded from a TOML file, or an empty log if the file does not exist yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the log.
+    pub fn load(path: &Path) -> Result<Self> {
+        return match fs::read_to_string(path) {
+            Ok(text) => Self::from_text(&text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::io(path, err)),
+        };
+    }
+
+    /// Returns the log parsed from the text of a TOML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The TOML text, with one `[[submission]]` table per checked answer.
+    pub fn from_text(text: &str) -> Result<Self> {
+        return toml::from_str(text).map_err(|err| toml_error(text, &err));
+    }
+
+    /// Writes the log to a TOML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the log.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = toml::to_string(self).expect("the submissions log is always valid TOML");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| Error::io(parent, err))?;
+        }
+        return fs::write(path, text).map_err(|err| Error::io(path, err));
+    }
+
+    /// Returns why an answer should not be submitted, or `None` if it might be right.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - the year of the event.
+    /// * `day` - the day of the event.
+    /// * `part` - the part of the puzzle.
+    /// * `answer` - the answer to submit.
+    /// * `now` - the current time, in seconds since the Unix epoch.
+    pub fn refusal(&self, year: u16, day: u8, part: u8, answer: &str, now: u64) -> Option<Refusal> {
+        if answer.trim().is_empty() || answer == UNSOLVED {
+            return Some(Refusal::Unsolved);
+        }
+        let previous = self
+            .submissions
+            .iter()
+            .filter(|submission| submission.year == year && submission.day == day && submission.part == part);
+        let number: Option<i64> = answer.parse().ok();
+        for submission in previous {
+            if submission.verdict == Verdict::Correct {
+                return Some(Refusal::AlreadySolved {
+                    answer: submission.answer.clone(),
+                });
+            }
+            if submission.answer == answer {
+                return Some(Refusal::AlreadyRejected {
+                    verdict: submission.verdict,
+                });
+            }
+            let Some((number, rejected)) = number.zip(submission.answer.parse::<i64>().ok()) else {
+                continue;
+            };
+            if (submission.verdict == Verdict::TooHigh && number > rejected)
+                || (submission.verdict == Verdict::TooLow && number < rejected)
+            {
+                return Some(Refusal::OutOfRange {
+                    rejected: submission.answer.clone(),
+                    verdict: submission.verdict,
+                });
+            }
+        }
+
+        return match self.wait_until {
+            Some(wait_until) if wait_until > now => Some(Refusal::TooSoon {
+                seconds: wait_until - now,
+            }),
+            _ => None,
+        };
+    }
+
+    /// Records the website's response to an answer. Only answers the website checked are kept,
+    /// along with how long to wait before the next submission.
+    ///
+    /// # Arguments
+    ///
+    /// * `submission` - the submitted answer and the website's verdict on it.
+    /// * `wait` - how many seconds the website asked to wait, if it did.
+    /// * `now` - the current time, in seconds since the Unix epoch.
+    pub fn record(&mut self, submission: Submission, wait: Option<u64>, now: u64) {
+        self.wait_until = wait.map(|seconds| now + seconds);
+        if submission.verdict == Verdict::Correct || submission.verdict.is_rejection() {
+            self.submissions.push(submission);
+        }
+    }
+
+    /// Returns every checked answer.
+    pub fn iter(&self) -> std::slice::Iter<'_, Submission> {
+        return self.submissions.iter();
+    }
+}
+
+/// What happened to an answer passed to [`submit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The answer was not sent, because the log shows it cannot be right or it is too soon.
+    Refused(Refusal),
+    /// The answer was sent and the website responded.
+    Submitted(Response),
+}
+
+/// Submits an answer unless the log shows it should not be, and records the response.
+///
+/// # Arguments
+///
+/// * `submitter` - where to submit the answer.
+/// * `submissions` - the log of previous submissions, updated with the response.
+/// * `year` - the year of the event.
+/// * `day` - the day of the event.
+/// * `part` - the part of the puzzle.
+/// * `answer` - the answer to submit.
+/// * `now` - the current time, in seconds since the Unix epoch.
+pub fn submit(
+    submitter: &dyn Submitter,
+    submissions: &mut Submissions,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Result<Outcome> {
+    if let Some(refusal) = submissions.refusal(year, day, part, answer, now) {
+        return Ok(Outcome::Refused(refusal));
+    }
+    let response = submitter.submit(year, day, part, answer)?;
+    let submission = Submission {
+        year,
+        day,
+        part,
+        answer: String::from(answer),
+        verdict: response.verdict,
+    };
+    submissions.record(submission, response.wait, now);
+    return Ok(Outcome::Submitted(response));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::serve_once;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are one gold star closer to saving your vacation.</p></article></main>";
+    const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.</p></article></main>";
+    const TOO_SOON: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.</p></article></main>";
+
+    struct CountingSubmitter {
+        calls: Arc<AtomicUsize>,
+        response: Response,
+    }
+
+    impl Submitter for CountingSubmitter {
+        fn submit(&self, _year: u16, _day: u8, _part: u8, _answer: &str) -> Result<Response> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            return Ok(self.response);
+        }
+    }
+
+    fn submission(answer: &str, verdict: Verdict) -> Submission {
+        return Submission {
+            year: 2022,
+            day: 1,
+            part: 1,
+            answer: String::from(answer),
+            verdict,
+        };
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(Verdict::Correct, Response::parse(CORRECT).unwrap().verdict);
+        assert_eq!(
+            Response {
+                verdict: Verdict::TooLow,
+                wait: Some(60)
+            },
+            Response::parse(TOO_LOW).unwrap()
+        );
+        assert_eq!(
+            Response {
+                verdict: Verdict::TooSoon,
+                wait: Some(83)
+            },
+            Response::parse(TOO_SOON).unwrap()
+        );
+        let wrong_level = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+        assert_eq!(Verdict::WrongLevel, Response::parse(wrong_level).unwrap().verdict);
+        assert!(matches!(Response::parse("<html>Puzzle inputs differ by user.</html>"), Err(Error::Parse { .. })));
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(Some(38), parse_wait("you have 38s left to wait."));
+        assert_eq!(Some(300), parse_wait("please wait 5 minutes before trying again."));
+        assert_eq!(None, parse_wait("that's the right answer!"));
+    }
+
+    #[test]
+    fn test_refusal() {
+        let mut submissions = Submissions::default();
+        submissions.record(submission("500", Verdict::TooHigh), None, 0);
+        submissions.record(submission("100", Verdict::TooLow), None, 0);
+        submissions.record(submission("abc", Verdict::Incorrect), Some(60), 0);
+
+        assert_eq!(
+            Some(Refusal::AlreadyRejected {
+                verdict: Verdict::Incorrect
+            }),
+            submissions.refusal(2022, 1, 1, "abc", 100)
+        );
+        assert_eq!(
+            Some(Refusal::OutOfRange {
+                rejected: String::from("500"),
+                verdict: Verdict::TooHigh
+            }),
+            submissions.refusal(2022, 1, 1, "501", 100)
+        );
+        assert!(matches!(submissions.refusal(2022, 1, 1, "99", 100), Some(Refusal::OutOfRange { .. })));
+        assert_eq!(Some(Refusal::TooSoon { seconds: 10 }), submissions.refusal(2022, 1, 1, "300", 50));
+        assert_eq!(None, submissions.refusal(2022, 1, 1, "300", 100));
+        assert_eq!(None, submissions.refusal(2022, 1, 2, "500", 100));
+
+        submissions.record(submission("300", Verdict::Correct), None, 100);
+        assert!(matches!(submissions.refusal(2022, 1, 1, "301", 100), Some(Refusal::AlreadySolved { .. })));
+    }
+
+    #[test]
+    fn test_refuses_unsolved_answers() {
+        let submissions = Submissions::default();
+        assert_eq!(Some(Refusal::Unsolved), submissions.refusal(2022, 5, 1, UNSOLVED, 0));
+        assert_eq!(Some(Refusal::Unsolved), submissions.refusal(2022, 5, 1, "", 0));
+        assert_eq!(Some(Refusal::Unsolved), submissions.refusal(2022, 5, 2, " \n", 0));
+        assert_eq!(None, submissions.refusal(2022, 5, 1, "CMZ", 0));
+    }
+
+    #[test]
+    fn test_record_skips_unchecked_answers() {
+        let mut submissions = Submissions::default();
+        submissions.record(submission("300", Verdict::TooSoon), Some(83), 0);
+        assert_eq!(0, submissions.iter().count());
+        assert_eq!(Some(Refusal::TooSoon { seconds: 83 }), submissions.refusal(2022, 1, 1, "300", 0));
+    }
+
+    #[test]
+    fn test_submissions_round_trip() {
+        let mut submissions = Submissions::default();
+        submissions.record(submission("500", Verdict::TooHigh), Some(60), 1000);
+        let path = std::env::temp_dir().join(format!("advent-of-code-submissions-{}.toml", std::process::id()));
+        submissions.save(&path).unwrap();
+        let loaded = Submissions::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(vec![submission("500", Verdict::TooHigh)], loaded.iter().cloned().collect::<Vec<Submission>>());
+        assert_eq!(Some(Refusal::TooSoon { seconds: 30 }), loaded.refusal(2022, 1, 1, "400", 1030));
+        assert_eq!(0, Submissions::load(&path).unwrap().iter().count());
+    }
+
+    #[test]
+    fn test_submit_refuses_without_sending() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let submitter = CountingSubmitter {
+            calls: calls.clone(),
+            response: Response {
+                verdict: Verdict::TooLow,
+                wait: None,
+            },
+        };
+        let mut submissions = Submissions::default();
+        assert!(matches!(
+            submit(&submitter, &mut submissions, 2022, 1, 1, "100", 0).unwrap(),
+            Outcome::Submitted(Response {
+                verdict: Verdict::TooLow,
+                ..
+            })
+        ));
+        assert!(matches!(
+            submit(&submitter, &mut submissions, 2022, 1, 1, "100", 0).unwrap(),
+            Outcome::Refused(Refusal::AlreadyRejected { .. })
+        ));
+        assert_eq!(
+            Outcome::Refused(Refusal::Unsolved),
+            submit(&submitter, &mut submissions, 2022, 5, 1, UNSOLVED, 0).unwrap()
+        );
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_http_submitter() {
+        let (base_url, server) = serve_once("200 OK", TOO_LOW);
+        let submitter = HttpSubmitter::with_base_url(&base_url, "secret");
+        let response = submitter.submit(2022, 1, 2, "12345").unwrap();
+        assert_eq!(Verdict::TooLow, response.verdict);
+
+        let request = server.join().unwrap();
+        assert_eq!("POST /2022/day/1/answer HTTP/1.1", request.line);
+        assert!(request.has_header("cookie: session=secret"));
+        assert_eq!("level=2&answer=12345", request.body);
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::answers::Answers;
+use crate::provider::InputProvider;
+use crate::registry::DAYS;
+use crate::runner::solve;
+use crate::solution::Day;
+
+/// How a computed answer compares with the answers manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The computed answer matches the recorded answer.
+    Pass,
+    /// The computed answer differs from the recorded answer.
+    Fail { expected: String },
+    /// There is no recorded answer to compare with.
+    Missing,
+    /// The day's puzzle input is not cached and could not be downloaded, so it was skipped.
+    NoInput,
+    /// The day's puzzle input could not be read or parsed.
+    Error { message: String },
+}
+
+/// The result of checking one part of a day's puzzle against the answers manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub status: Status,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} Day {:>2} Part {}: ", self.year, self.day, self.part)?;
+        return match &self.status {
+            Status::Pass => write!(f, "pass ({})", self.answer),
+            Status::Fail { expected } => write!(f, "FAIL (expected {}, got {})", expected, self.answer),
+            Status::Missing => write!(f, "missing (got {})", self.answer),
+            Status::NoInput => write!(f, "no input"),
+            Status::Error { message } => write!(f, "ERROR ({})", message),
+        };
+    }
+}
+
+/// Returns how an answer to a part of a day's puzzle, computed from its cached input, compares
+/// with the answers manifest.
+///
+/// # Arguments
+///
+/// * `answers` - the answers manifest.
+/// * `day` - the registered day.
+/// * `part` - the part of the puzzle.
+/// * `answer` - the computed answer.
+pub fn status(answers: &Answers, day: &Day, part: u8, answer: &str) -> Status {
+    let input = InputProvider::relative_path(day.year, day.number);
+    return match answers.find(day.year, day.number, part, &input) {
+        Some(recorded) if recorded.answer == answer => Status::Pass,
+        Some(recorded) => Status::Fail {
+            expected: recorded.answer.clone(),
+        },
+        None => Status::Missing,
+    };
+}
+
+/// Returns the outcome of running every registered day against its puzzle input and
+/// comparing both parts with the answers manifest. A day whose input is missing or cannot be
+/// parsed gets a `NoInput` or `Error` outcome for both parts, and the other days still run.
+///
+/// # Arguments
+///
+/// * `answers` - the answers manifest.
+/// * `inputs` - where to find each day's puzzle input.
+pub fn verify(answers: &Answers, inputs: &InputProvider) -> Vec<Outcome> {
+    let mut outcomes: Vec<Outcome> = Vec::new();
+    for day in DAYS.iter() {
+        let solution = match inputs.get(day.year, day.number).and_then(|text| day.parse(&text)) {
+            Ok(solution) => solution,
+            Err(err) => {
+                let status = match err.is_not_found() {
+                    true => Status::NoInput,
+                    false => Status::Error { message: err.to_string() },
+                };
+                for part in [1, 2] {
+                    outcomes.push(Outcome {
+                        year: day.year,
+                        day: day.number,
+                        part,
+                        answer: String::new(),
+                        status: status.clone(),
+                    });
+                }
+                continue;
+            }
+        };
+        for part in [1, 2] {
+            let answer = solve(solution.as_ref(), part);
+            let status = status(answers, day, part, &answer);
+            outcomes.push(Outcome {
+                year: day.year,
+                day: day.number,
+                part,
+                answer,
+                status,
+            });
+        }
+    }
+    return outcomes;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::example_inputs;
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::from_text(
+            r#"
+[[answer]]
+year = 2022
+day = 4
+part = 1
+input = "2022/4.txt"
+answer = "2"
+
+[[answer]]
+year = 2022
+day = 4
+part = 2
+input = "2022/4.txt"
+answer = "1"
+"#,
+        )
+        .unwrap();
+        let cache = example_inputs("verify");
+        let outcomes = verify(&answers, &InputProvider::new(&cache));
+        let day_four: Vec<&Outcome> = outcomes.iter().filter(|outcome| outcome.day == 4).collect();
+        assert_eq!(Status::Pass, day_four[0].status);
+        assert_eq!(
+            Status::Fail {
+                expected: String::from("1")
+            },
+            day_four[1].status
+        );
+        assert_eq!(Status::Missing, outcomes[0].status);
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_verify_carries_on_past_bad_inputs() {
+        let cache = std::env::temp_dir().join(format!("advent-of-code-verify-{}", std::process::id()));
+        std::fs::create_dir_all(cache.join("2022")).unwrap();
+        std::fs::write(cache.join("2022/2.txt"), "A Q\n").unwrap();
+        std::fs::write(cache.join("2022/4.txt"), "2-4,6-8\n").unwrap();
+
+        let outcomes = verify(&Answers::default(), &InputProvider::new(&cache));
+        let status = |day: u8| outcomes.iter().find(|outcome| outcome.day == day).unwrap().status.clone();
+        assert_eq!(Status::NoInput, status(1));
+        assert!(matches!(status(2), Status::Error { message } if message.contains("line 1")));
+        assert_eq!(Status::Missing, status(4));
+        assert_eq!(DAYS.len() * 2, outcomes.len());
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_display() {
+        let outcome = Outcome {
+            year: 2022,
+            day: 3,
+            part: 2,
+            answer: String::from("12"),
+            status: Status::Fail {
+                expected: String::from("70"),
+            },
+        };
+        assert_eq!("2022 Day  3 Part 2: FAIL (expected 70, got 12)", outcome.to_string());
+    }
+}
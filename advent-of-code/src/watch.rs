@@ -0,0 +1,245 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher as _};
+
+use crate::error::{Error, Result};
+use crate::runner::solve;
+use crate::solution::Day;
+
+/// How long to keep collecting events after the first one, so that an editor saving a file in
+/// several steps only causes one re-run.
+const SETTLE: Duration = Duration::from_millis(100);
+
+/// Returns the example inputs of a day, found at `src/y<year>/day_<day>/examples/*.txt`.
+///
+/// # Arguments
+///
+/// * `root` - the crate directory.
+/// * `day` - the registered day.
+pub fn example_files(root: &Path, day: &Day) -> Result<Vec<PathBuf>> {
+    let dir = root.join(format!("src/y{}/day_{}/examples", day.year, day.number));
+    let mut files: Vec<PathBuf> = Vec::new();
+    for entry in fs::read_dir(&dir).map_err(|err| Error::io(&dir, err))? {
+        let path = entry.map_err(|err| Error::io(&dir, err))?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    return Ok(files);
+}
+
+/// The answers to both parts of a day's puzzle for each watched file. A file that cannot be
+/// read or parsed has the error in place of its answers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    answers: BTreeMap<(PathBuf, u8), String>,
+}
+
+impl Snapshot {
+    /// Returns the answers for each file, parsing it and solving both parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `day` - the registered day to run.
+    /// * `files` - the puzzle inputs to run it against.
+    pub fn take(day: &Day, files: &[PathBuf]) -> Self {
+        let mut answers: BTreeMap<(PathBuf, u8), String> = BTreeMap::new();
+        for file in files.iter() {
+            let solution = fs::read_to_string(file)
+                .map_err(|err| Error::io(file, err))
+                .and_then(|text| day.parse(&text));
+            for part in [1, 2] {
+                let answer = match &solution {
+                    Ok(solution) => solve(solution.as_ref(), part),
+                    Err(err) => format!("error: {}", err),
+                };
+                answers.insert((file.clone(), part), answer);
+            }
+        }
+        return Self { answers };
+    }
+
+    /// Returns one line per file and part, marking the answers that differ from a previous
+    /// snapshot as `before -> after`.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - the snapshot to compare with, or `None` on the first run.
+    /// * `root` - the directory the file names are shown relative to.
+    pub fn diff(&self, previous: Option<&Snapshot>, root: &Path) -> Vec<String> {
+        let mut lines: Vec<String> = Vec::new();
+        for ((file, part), answer) in self.answers.iter() {
+            let name = file.strip_prefix(root).unwrap_or(file).display();
+            let before = previous.and_then(|previous| previous.answers.get(&(file.clone(), *part)));
+            lines.push(match before {
+                Some(before) if before != answer => format!("* {} part {}: {} -> {}", name, part, before, answer),
+                None if previous.is_some() => format!("+ {} part {}: {}", name, part, answer),
+                _ => format!("  {} part {}: {}", name, part, answer),
+            });
+        }
+        return lines;
+    }
+}
+
+/// Waits for changes to a set of files, using inotify on Linux.
+pub struct Watcher {
+    files: BTreeSet<PathBuf>,
+    events: Receiver<notify::Result<Event>>,
+    // Dropping the watcher stops the events.
+    _watcher: RecommendedWatcher,
+}
+
+impl Watcher {
+    /// Returns a watcher for the files. Their directories are watched rather than the files
+    /// themselves, so that files replaced by an editor or created later are still seen, and any
+    /// directory that does not exist yet is created, such as the inputs of an event not started.
+    ///
+    /// # Arguments
+    ///
+    /// * `files` - the files to watch.
+    pub fn new(files: &[PathBuf]) -> Result<Self> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender).map_err(|err| watch_error(Path::new("."), err))?;
+        let dirs: BTreeSet<&Path> = files.iter().filter_map(|file| file.parent()).collect();
+        for dir in dirs {
+            fs::create_dir_all(dir).map_err(|err| Error::io(dir, err))?;
+            watcher
+                .watch(dir, RecursiveMode::NonRecursive)
+                .map_err(|err| watch_error(dir, err))?;
+        }
+        return Ok(Self {
+            files: files.iter().cloned().collect(),
+            events,
+            _watcher: watcher,
+        });
+    }
+
+    /// Blocks until at least one of the files changes, returning every file that changed.
+    pub fn wait(&self) -> Result<Vec<PathBuf>> {
+        let mut changed: BTreeSet<PathBuf> = BTreeSet::new();
+        while changed.is_empty() {
+            let event = self.events.recv().map_err(|err| Error::from(io::Error::other(err)))?;
+            self.collect(event, &mut changed)?;
+        }
+        while let Ok(event) = self.events.recv_timeout(SETTLE) {
+            self.collect(event, &mut changed)?;
+        }
+        return Ok(changed.into_iter().collect());
+    }
+
+    fn collect(&self, event: notify::Result<Event>, changed: &mut BTreeSet<PathBuf>) -> Result<()> {
+        let event = event.map_err(|err| watch_error(Path::new("."), err))?;
+        if event.kind.is_access() {
+            return Ok(());
+        }
+        changed.extend(event.paths.into_iter().filter(|path| self.files.contains(path)));
+        return Ok(());
+    }
+}
+
+fn watch_error(path: &Path, err: notify::Error) -> Error {
+    return Error::io(path, io::Error::other(err));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::find_day;
+    use std::env;
+    use std::thread;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("advent-of-code-watch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        return dir;
+    }
+
+    #[test]
+    fn test_example_files() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let files = example_files(root, find_day(2022, 4).unwrap()).unwrap();
+        assert_eq!(vec![root.join("src/y2022/day_4/examples/example.txt")], files);
+    }
+
+    #[test]
+    fn test_snapshot_diff() {
+        let dir = temp_dir("diff");
+        let day = find_day(2022, 4).unwrap();
+        let files = vec![dir.join("a.txt"), dir.join("b.txt")];
+        fs::write(&files[0], "2-4,6-8\n2-8,3-7\n").unwrap();
+        fs::write(&files[1], "2-8,3-7\n").unwrap();
+        let first = Snapshot::take(day, &files);
+        assert_eq!(
+            vec!["  a.txt part 1: 1", "  a.txt part 2: 1", "  b.txt part 1: 1", "  b.txt part 2: 1"],
+            first.diff(None, &dir)
+        );
+
+        fs::write(&files[0], "2-4,6-8\n5-7,7-9\n").unwrap();
+        fs::write(&files[1], "2-8\n").unwrap();
+        let second = Snapshot::take(day, &files);
+        let lines = second.diff(Some(&first), &dir);
+        assert_eq!("* a.txt part 1: 1 -> 0", lines[0]);
+        assert_eq!("  a.txt part 2: 1", lines[1]);
+        assert!(lines[2].starts_with("* b.txt part 1: 1 -> error: "), "{}", lines[2]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_snapshot_diff_new_file() {
+        let dir = temp_dir("new-file");
+        let day = find_day(2022, 4).unwrap();
+        let file = dir.join("a.txt");
+        let before = Snapshot::take(day, &[]);
+        fs::write(&file, "2-8,3-7\n").unwrap();
+        let after = Snapshot::take(day, &[file]);
+        assert_eq!("+ a.txt part 1: 1", after.diff(Some(&before), &dir)[0]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_watcher_sees_change() {
+        let dir = temp_dir("change");
+        let watched = dir.join("input.txt");
+        let ignored = dir.join("other.txt");
+        fs::write(&watched, "").unwrap();
+        let watcher = Watcher::new(std::slice::from_ref(&watched)).unwrap();
+
+        let writer = {
+            let (watched, ignored) = (watched.clone(), ignored.clone());
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                fs::write(&ignored, "1").unwrap();
+                fs::write(&watched, "2-8,3-7\n").unwrap();
+            })
+        };
+        assert_eq!(vec![watched], watcher.wait().unwrap());
+        writer.join().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_watcher_creates_missing_dir() {
+        let dir = temp_dir("missing");
+        let watched = dir.join("2022/1.txt");
+        let watcher = Watcher::new(std::slice::from_ref(&watched)).unwrap();
+        assert!(dir.join("2022").is_dir());
+
+        let writer = {
+            let watched = watched.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                fs::write(&watched, "1000\n").unwrap();
+            })
+        };
+        assert_eq!(vec![watched], watcher.wait().unwrap());
+        writer.join().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,534 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::error::{Error, Result};
+use crate::input;
+use crate::solution::Solution;
+
+pub mod statistics;
+
+/// What to do with elves tied on calories with the last elf of a top N query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ties {
+    /// Return exactly N elves, preferring the lowest elf IDs among the tied ones.
+    Break,
+    /// Return every elf tied with the last one, so there may be more than N.
+    Keep,
+}
+
+/// Identifies one elf of an `Elves` instance. IDs are handed out in the order elves are added,
+/// starting from 0, so a lower ID means the elf was listed earlier in the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ElfId(usize);
+
+impl ElfId {
+    /// Returns the position of the elf in the order elves were added, starting from 0.
+    pub fn index(&self) -> usize {
+        return self.0;
+    }
+}
+
+impl fmt::Display for ElfId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "elf {}", self.0);
+    }
+}
+
+/// An instance for storing all of the elves and their calories info.
+#[derive(Debug)]
+pub struct Elves {
+    /// All of the elves stored in this instance.
+    elves: HashMap<ElfId, Elf>,
+    /// the ID the next elf added to the Elves instance will get.
+    next_id: ElfId,
+}
+
+impl Default for Elves {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl Elves {
+    /// Returns an Elves instance with an empty HashMap for the elves and a tracker for the next ID.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use advent_of_code::y2022::day_1::Elves;
+    /// let mut elves: Elves = Elves::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            elves: HashMap::new(),
+            next_id: ElfId(0),
+        }
+    }
+
+    /// Returns an Elves instance with a filled elves HashMap loaded from a text file.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `filepath` - The full path to the file to load into the Elves instance.
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// use advent_of_code::y2022::day_1::Elves;
+    /// let mut elves: Elves = Elves::load_elves(String::from("path/to/file.txt"))?;
+    /// # Ok::<(), advent_of_code::Error>(())
+    /// ```
+    pub fn load_elves(filepath: String) -> Result<Self> {
+        return Self::from_reader(input::open(&filepath)?);
+    }
+
+    /// Returns an Elves instance loaded from the text of a puzzle input.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The calories carried by each elf, with a blank line between elves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::y2022::day_1::Elves;
+    /// let elves: Elves = Elves::from_text("1000\n2000\n\n3000\n")?;
+    /// assert_eq!(elves.get_total_elves(), 2);
+    /// # Ok::<(), advent_of_code::Error>(())
+    /// ```
+    pub fn from_text(text: &str) -> Result<Self> {
+        return Self::from_reader(text.as_bytes());
+    }
+
+    /// Returns an Elves instance loaded from the puzzle input piped to stdin.
+    pub fn from_stdin() -> Result<Self> {
+        return Self::from_reader(io::stdin().lock());
+    }
+
+    /// Returns an Elves instance loaded from any buffered reader over a puzzle input.
+    ///
+    /// An elf starts at the first food item after a blank line, or at the start of the input, so
+    /// blank lines at either end or several in a row never create an elf without food. Lines may
+    /// end in `\r\n` and have whitespace around the calories.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The reader to load the elves from, one food item per line.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self> {
+        let mut instance = Self::new();
+        let mut elf_id: Option<ElfId> = None;
+
+        for (index, line) in reader.lines().enumerate() {
+            let ip = line?;
+            let text = ip.trim();
+            if text.is_empty() {
+                elf_id = None;
+                continue;
+            }
+            let calories = text
+                .parse::<i32>()
+                .map_err(|_| Error::parse(text, "expected the calories of a food item").at_line(index + 1))?;
+            let id = *elf_id.get_or_insert_with(|| instance.add_empty_elf());
+            instance.add_food_to_elf(Food::new(calories), id)?;
+        }
+        return Ok(instance);
+    }
+
+    /// Returns the elf ID for the elf with the most calories, or `None` if there are no elves.
+    /// Ties go to the lowest elf ID.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use advent_of_code::y2022::day_1::{ElfId, Elves, Food};
+    /// let mut elves: Elves = Elves::new();
+    /// 
+    /// let elf_id_1: ElfId = elves.add_empty_elf();
+    /// let elf_id_2: ElfId = elves.add_empty_elf();
+    /// let elf_id_3: ElfId = elves.add_empty_elf();
+    /// 
+    /// elves.add_food_to_elf(Food::new(100), elf_id_1)?;
+    /// elves.add_food_to_elf(Food::new(150), elf_id_2)?;
+    /// elves.add_food_to_elf(Food::new(200), elf_id_3)?;
+    /// 
+    /// let elf_id = elves.get_elf_id_with_most_calories();
+    /// assert_eq!(elf_id, Some(elf_id_3));
+    /// # Ok::<(), advent_of_code::Error>(())
+    /// ```
+    pub fn get_elf_id_with_most_calories(&self) -> Option<ElfId> {
+        return self.get_top_calories_elf_ids(1, Ties::Break).first().copied();
+    }
+
+    /// Return the Elf IDs for the Elves with the top N calories, or every elf if there are fewer
+    /// than N. Ties are broken by the lowest elf ID, as for `get_top_calories_elf_ids`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `n` - the number of Elf IDs to return.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use advent_of_code::y2022::day_1::{ElfId, Elves, Food};
+    /// let mut elves: Elves = Elves::new();
+    /// 
+    /// let elf_id_1: ElfId = elves.add_empty_elf();
+    /// let elf_id_2: ElfId = elves.add_empty_elf();
+    /// let elf_id_3: ElfId = elves.add_empty_elf();
+    /// 
+    /// 
+    /// elves.add_food_to_elf(Food::new(100), elf_id_1)?;
+    /// elves.add_food_to_elf(Food::new(150), elf_id_2)?;
+    /// elves.add_food_to_elf(Food::new(200), elf_id_3)?;
+    /// 
+    /// let ids: Vec<ElfId> = elves.get_top_n_calories_elf_ids(2);
+    /// assert_eq!(ids, vec![elf_id_3, elf_id_2]);
+    /// # Ok::<(), advent_of_code::Error>(())
+    /// ```
+    pub fn get_top_n_calories_elf_ids(&self, n: u8) -> Vec<ElfId> {
+        return self.get_top_calories_elf_ids(n as usize, Ties::Break);
+    }
+
+    /// Returns the Elf IDs for the Elves with the most calories, from most to fewest calories
+    /// and then from lowest to highest elf ID. Only `n` elves are kept in a heap while scanning,
+    /// so this takes O(elves · log n) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - the number of Elf IDs to return.
+    /// * `ties` - whether elves tied with the last one returned are left out or added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::y2022::day_1::{Elves, Ties};
+    /// let elves: Elves = Elves::from_text("300\n\n200\n\n100\n100\n\n50\n")?;
+    ///
+    /// let indexes = |ties| elves.get_top_calories_elf_ids(2, ties).iter().map(|id| id.index()).collect::<Vec<usize>>();
+    /// assert_eq!(indexes(Ties::Break), vec![0, 1]);
+    /// assert_eq!(indexes(Ties::Keep), vec![0, 1, 2]);
+    /// # Ok::<(), advent_of_code::Error>(())
+    /// ```
+    pub fn get_top_calories_elf_ids(&self, n: usize, ties: Ties) -> Vec<ElfId> {
+        if n == 0 {
+            return Vec::new();
+        }
+        // The heap's smallest entry is the worst elf kept so far: fewest calories, then highest ID.
+        let mut heap: BinaryHeap<Reverse<(i32, Reverse<ElfId>)>> = BinaryHeap::with_capacity(n + 1);
+        for (elf_id, elf) in self.elves.iter() {
+            heap.push(Reverse((elf.get_total_calories(), Reverse(*elf_id))));
+            if heap.len() > n {
+                heap.pop();
+            }
+        }
+        let mut top: Vec<(i32, Reverse<ElfId>)> = heap.into_iter().map(|Reverse(entry)| entry).collect();
+
+        // The tied elves that were kept have the lowest IDs, so the rest have higher ones.
+        if let (Ties::Keep, Some(&(cutoff, Reverse(last_id)))) = (ties, top.iter().min()) {
+            for (elf_id, elf) in self.elves.iter() {
+                if elf.get_total_calories() == cutoff && *elf_id > last_id {
+                    top.push((cutoff, Reverse(*elf_id)));
+                }
+            }
+        }
+        top.sort_by(|a, b| b.cmp(a));
+        return top.into_iter().map(|(_, Reverse(elf_id))| elf_id).collect();
+    }
+
+    /// Returns the total number of calories carried by some elves, or `None` if any of them are
+    /// not in this instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `elf_ids` - the IDs of the elves.
+    pub fn get_total_calories_for_elf_ids(&self, elf_ids: &[ElfId]) -> Option<i32> {
+        let mut total_calories: i32 = 0;
+        for elf_id in elf_ids.iter() {
+            total_calories += self.get_elf_total_calories(*elf_id)?;
+        }
+        return Some(total_calories);
+    }
+
+    /// Return the total number of calories for an elf, or `None` if there is no such elf.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `elf_id` - the ID for the elf.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use advent_of_code::y2022::day_1::{ElfId, Elves, Food};
+    /// let mut elves: Elves = Elves::new();
+    /// 
+    /// let elf_id_1: ElfId = elves.add_empty_elf();
+    /// elves.add_food_to_elf(Food::new(100), elf_id_1)?;
+    /// 
+    /// let total_calories = elves.get_elf_total_calories(elf_id_1);
+    /// assert_eq!(total_calories, Some(100));
+    /// # Ok::<(), advent_of_code::Error>(())
+    /// ```
+    pub fn get_elf_total_calories(&self, elf_id: ElfId) -> Option<i32> {
+        return self.elves.get(&elf_id).map(|elf| elf.get_total_calories());
+    }
+
+    /// Returns the total number of elves in the vector.
+    /// 
+    /// # Examples
+    /// ```
+    /// use advent_of_code::y2022::day_1::Elves;
+    /// let mut elves: Elves = Elves::new();
+    /// assert_eq!(elves.get_total_elves(), 0);
+    /// 
+    /// elves.add_empty_elf();
+    /// elves.add_empty_elf();
+    /// elves.add_empty_elf();
+    /// assert_eq!(elves.get_total_elves(), 3);
+    /// ```
+    pub fn get_total_elves(&self) -> usize {
+        return self.elves.len();
+    }
+
+    /// Add a new elf to the Elves instance.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `new_elf` - add new Elf instance to the vector of elves.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use advent_of_code::y2022::day_1::{Elf, ElfId, Elves};
+    /// let mut elves: Elves = Elves::new();
+    /// 
+    /// let elf_id: ElfId = elves.add_elf(Elf::new());
+    /// ```
+    pub fn add_elf(&mut self, new_elf: Elf) -> ElfId {
+        let id = self.next_id;
+        self.next_id = ElfId(id.0 + 1);
+        self.elves.insert(id, new_elf);
+        return id;
+    }
+
+    /// Add a new empty elf to the Elves instance.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use advent_of_code::y2022::day_1::{ElfId, Elves};
+    /// let mut elves: Elves = Elves::new();
+    /// 
+    /// let elf_id: ElfId = elves.add_empty_elf();
+    /// ```
+    pub fn add_empty_elf(&mut self) -> ElfId {
+        return self.add_elf(Elf::new());
+    }
+
+    /// Return the ID for the last elf added to the vector, or `None` if no elves have been added.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use advent_of_code::y2022::day_1::{ElfId, Elves};
+    /// let mut elves: Elves = Elves::new();
+    /// assert_eq!(elves.get_last_id(), None);
+    /// 
+    /// let elf_id_1: ElfId = elves.add_empty_elf();
+    /// assert_eq!(elves.get_last_id(), Some(elf_id_1));
+    /// 
+    /// let elf_id_2: ElfId = elves.add_empty_elf();
+    /// assert_eq!(elves.get_last_id(), Some(elf_id_2));
+    /// ```
+    pub fn get_last_id(&self) -> Option<ElfId> {
+        return self.next_id.0.checked_sub(1).map(ElfId);
+    }
+
+    /// Add food to a specified elf, or return an error if there is no such elf.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `food` - the food to be added to the specified elf.
+    /// * `elf_id` - the ID for the specified elf.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use advent_of_code::y2022::day_1::{ElfId, Elves, Food};
+    /// let mut elves: Elves = Elves::new();
+    /// 
+    /// let elf_id: ElfId = elves.add_empty_elf();
+    /// elves.add_food_to_elf(Food::new(1000), elf_id)?;
+    /// # Ok::<(), advent_of_code::Error>(())
+    /// ```
+    pub fn add_food_to_elf(&mut self, food: Food, elf_id: ElfId) -> Result<()> {
+        let Some(elf) = self.elves.get_mut(&elf_id) else {
+            return Err(Error::Validation(format!("there is no elf {} to add food to", elf_id.0)));
+        };
+        elf.add_food(food);
+        return Ok(());
+    }
+}
+
+impl Solution for Elves {
+    fn parse(input: &str) -> Result<Self> {
+        return Self::from_text(input);
+    }
+
+    fn part_one(&self) -> String {
+        let most = self.get_elf_id_with_most_calories().and_then(|elf_id| self.get_elf_total_calories(elf_id));
+        return most.unwrap_or(0).to_string();
+    }
+
+    fn part_two(&self) -> String {
+        let top_three = self.get_total_calories_for_elf_ids(&self.get_top_n_calories_elf_ids(3));
+        return top_three.unwrap_or(0).to_string();
+    }
+}
+
+#[derive(Debug)]
+pub struct Elf {
+    foods: Vec<Food>,
+}
+
+impl Default for Elf {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl Elf {
+    pub fn new() -> Self {
+        Self { foods: Vec::new() }
+    }
+
+    pub fn add_food(&mut self, new_food: Food) {
+        self.foods.push(new_food)
+    }
+
+    pub fn add_food_by_calories(&mut self, calories: i32) {
+        self.foods.push(Food::new(calories));
+    }
+
+    pub fn get_total_foods(&self) -> usize {
+        return self.foods.len();
+    }
+
+    pub fn get_total_calories(&self) -> i32 {
+        let mut total_calories: i32 = 0;
+        for food in self.foods.iter() {
+            total_calories += food.get_calories();
+        }
+        return total_calories;
+    }
+}
+
+#[derive(Debug)]
+pub struct Food {
+    calories: i32,
+}
+
+impl Food {
+    pub fn new(calories: i32) -> Self {
+        Self { calories }
+    }
+
+    pub fn get_calories(&self) -> i32 {
+        return self.calories;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_missing_file() {
+        let result = Elves::load_elves(String::from("src/y2022/day_1/missing.txt"));
+        assert!(matches!(result, Err(Error::Io { .. })));
+    }
+
+    #[test]
+    fn test_from_text() {
+        let cases = [
+            ("\n1000\n2000\n3000\n\n4000\n", 2, Some(6000)),
+            ("\n1000\n\n2000\n\n3000", 3, Some(3000)),
+            ("\n100\n200", 1, Some(300)),
+            ("1000\n2000\n\n4000\n", 2, Some(4000)),
+            ("7000\n\n1000\n2000", 2, Some(7000)),
+            ("1000\r\n2000\r\n\r\n2500 \r\n", 2, Some(3000)),
+            ("\n\n1000\n\n\n\n2000\n\n\n", 2, Some(2000)),
+            (" 1000\t\n  \n3000", 2, Some(3000)),
+            ("", 0, None),
+            ("\n\n", 0, None),
+        ];
+        for (text, total_elves, most_calories) in cases {
+            let elves = Elves::from_text(text).unwrap();
+            assert_eq!(total_elves, elves.get_total_elves(), "{:?}", text);
+            let most = elves.get_elf_id_with_most_calories().and_then(|elf_id| elves.get_elf_total_calories(elf_id));
+            assert_eq!(most_calories, most, "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_from_text_invalid_calories() {
+        let error = Elves::from_text("\n1000\nlots\n").unwrap_err();
+        assert_eq!("line 3: expected the calories of a food item (found \"lots\")", error.to_string());
+
+        let error = Elves::from_text("1000\r\n\r\n2000\r\n-\r\n").unwrap_err();
+        assert_eq!("line 4: expected the calories of a food item (found \"-\")", error.to_string());
+        assert!(Elves::from_text("1000\n20 00\n").is_err());
+    }
+
+    #[test]
+    fn test_top_calories_breaks_ties_by_lowest_id() {
+        let elves = Elves::from_text("500\n\n700\n\n500\n\n700\n\n500\n\n100\n").unwrap();
+        let ids = |indexes: &[usize]| indexes.iter().map(|index| ElfId(*index)).collect::<Vec<ElfId>>();
+        for _ in 0..10 {
+            assert_eq!(ids(&[1, 3, 0]), elves.get_top_calories_elf_ids(3, Ties::Break));
+        }
+        assert_eq!(ids(&[1, 3, 0, 2, 4]), elves.get_top_calories_elf_ids(3, Ties::Keep));
+        assert_eq!(ids(&[1, 3]), elves.get_top_calories_elf_ids(2, Ties::Keep));
+        assert_eq!(ids(&[1, 3, 0, 2, 4, 5]), elves.get_top_calories_elf_ids(10, Ties::Break));
+        assert_eq!(ids(&[]), elves.get_top_calories_elf_ids(0, Ties::Keep));
+        assert_eq!(Some(ElfId(1)), elves.get_elf_id_with_most_calories());
+    }
+
+    #[test]
+    fn test_top_calories_without_elves() {
+        let elves = Elves::new();
+        assert_eq!(Vec::<ElfId>::new(), elves.get_top_n_calories_elf_ids(3));
+        assert_eq!(None, elves.get_elf_id_with_most_calories());
+        assert_eq!(Some(0), elves.get_total_calories_for_elf_ids(&elves.get_top_n_calories_elf_ids(3)));
+        assert_eq!("0", elves.part_one());
+    }
+
+    #[test]
+    fn test_unknown_elf() {
+        let mut elves = Elves::from_text("1000\n\n2000\n").unwrap();
+        assert_eq!(None, elves.get_elf_total_calories(ElfId(2)));
+        assert_eq!(None, elves.get_total_calories_for_elf_ids(&[ElfId(0), ElfId(2)]));
+        assert_eq!(Some(3000), elves.get_total_calories_for_elf_ids(&[ElfId(0), ElfId(1)]));
+
+        let error = elves.add_food_to_elf(Food::new(500), ElfId(2)).unwrap_err();
+        assert_eq!("invalid puzzle input: there is no elf 2 to add food to", error.to_string());
+        assert_eq!(2, elves.get_total_elves());
+    }
+
+    #[test]
+    fn test_add_food_to_missing_elf() {
+        let mut elves = Elves::new();
+        let error = elves.add_food_to_elf(Food::new(500), ElfId(0)).unwrap_err();
+        assert!(matches!(error, Error::Validation(_)));
+        assert_eq!("invalid puzzle input: there is no elf 0 to add food to", error.to_string());
+        assert_eq!(0, elves.get_total_elves());
+    }
+
+    #[test]
+    fn test_from_text_keeps_first_elf() {
+        let elves = Elves::from_text("1000\n2000\n3000\n\n4000\n\n5000\n6000\n").unwrap();
+        assert_eq!(3, elves.get_total_elves());
+        assert_eq!(Some(6000), elves.get_elf_total_calories(ElfId(0)));
+    }
+}
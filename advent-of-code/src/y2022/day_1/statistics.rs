@@ -0,0 +1,278 @@
+use super::{ElfId, Elves};
+use crate::percentile::nearest_rank;
+
+/// The most buckets a histogram is split into, however many are asked for.
+pub const MAX_BUCKETS: usize = 1000;
+
+/// Summary statistics of the total calories carried by each elf.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalorieStatistics {
+    /// Every elf's total calories, from fewest to most.
+    totals: Vec<i32>,
+}
+
+impl CalorieStatistics {
+    /// Returns the statistics for a set of totals, in any order, or `None` if there are none.
+    ///
+    /// # Arguments
+    ///
+    /// * `totals` - the total calories carried by each elf.
+    pub fn new(mut totals: Vec<i32>) -> Option<Self> {
+        if totals.is_empty() {
+            return None;
+        }
+        totals.sort();
+        return Some(Self { totals });
+    }
+
+    /// Returns the number of elves.
+    pub fn count(&self) -> usize {
+        return self.totals.len();
+    }
+
+    /// Returns the fewest calories carried by one elf.
+    pub fn min(&self) -> i32 {
+        return self.totals[0];
+    }
+
+    /// Returns the most calories carried by one elf.
+    pub fn max(&self) -> i32 {
+        return self.totals[self.totals.len() - 1];
+    }
+
+    /// Returns the mean of the elves' total calories.
+    pub fn mean(&self) -> f64 {
+        return self.totals.iter().map(|total| *total as f64).sum::<f64>() / self.count() as f64;
+    }
+
+    /// Returns the median of the elves' total calories, halfway between the two middle elves when
+    /// there is an even number of them.
+    pub fn median(&self) -> f64 {
+        let middle = self.count() / 2;
+        if self.count() % 2 == 1 {
+            return self.totals[middle] as f64;
+        }
+        return (self.totals[middle - 1] as f64 + self.totals[middle] as f64) / 2.0;
+    }
+
+    /// Returns the total that the given percentage of elves carry at most, by the nearest-rank
+    /// method.
+    ///
+    /// # Arguments
+    ///
+    /// * `percent` - the percentile, from 0 to 100.
+    pub fn percentile(&self, percent: usize) -> i32 {
+        return nearest_rank(&self.totals, percent);
+    }
+
+    /// Returns the population standard deviation of the elves' total calories.
+    pub fn standard_deviation(&self) -> f64 {
+        let mean = self.mean();
+        let variance = self.totals.iter().map(|total| (*total as f64 - mean).powi(2)).sum::<f64>() / self.count() as f64;
+        return variance.sqrt();
+    }
+}
+
+/// A range of total calories and the number of elves whose total falls in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    /// The smallest total in the bucket.
+    pub start: i32,
+    /// The largest total in the bucket, inclusive.
+    pub end: i32,
+    pub count: usize,
+}
+
+/// The elves' total calories counted in buckets of equal width.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    buckets: Vec<Bucket>,
+}
+
+impl Histogram {
+    /// Returns a histogram of a set of totals, with at most `buckets` buckets of equal width
+    /// spanning the smallest to the largest total.
+    ///
+    /// # Arguments
+    ///
+    /// * `totals` - the total calories carried by each elf.
+    /// * `buckets` - the most buckets to use. Zero is treated as one, and anything over
+    ///   `MAX_BUCKETS` as `MAX_BUCKETS`.
+    pub fn new(totals: &[i32], buckets: usize) -> Self {
+        let (Some(min), Some(max)) = (totals.iter().min(), totals.iter().max()) else {
+            return Self { buckets: Vec::new() };
+        };
+        let span = (*max as i64 - *min as i64) as u64 + 1;
+        let width = span.div_ceil(buckets.clamp(1, MAX_BUCKETS) as u64);
+        let mut counts = vec![0; span.div_ceil(width) as usize];
+        for total in totals.iter() {
+            counts[((*total as i64 - *min as i64) as u64 / width) as usize] += 1;
+        }
+
+        let buckets = counts
+            .into_iter()
+            .enumerate()
+            .map(|(index, count)| {
+                let start = *min as i64 + (index as u64 * width) as i64;
+                return Bucket {
+                    start: start as i32,
+                    end: (start + width as i64 - 1).min(*max as i64) as i32,
+                    count,
+                };
+            })
+            .collect();
+        return Self { buckets };
+    }
+
+    /// Returns the buckets, from the smallest totals to the largest.
+    pub fn buckets(&self) -> &[Bucket] {
+        return &self.buckets;
+    }
+
+    /// Returns the histogram as a bar chart, one line per bucket, such as
+    /// `1000-1999 | ████████ 4`. Every non-empty bucket gets a bar of at least one block.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - the length of the bar for the fullest bucket.
+    pub fn render(&self, width: usize) -> String {
+        let most = self.buckets.iter().map(|bucket| bucket.count).max().unwrap_or(0).max(1);
+        let labels: Vec<String> = self.buckets.iter().map(|bucket| format!("{}-{}", bucket.start, bucket.end)).collect();
+        let label_width = labels.iter().map(|label| label.len()).max().unwrap_or(0);
+
+        let mut lines: Vec<String> = Vec::new();
+        for (label, bucket) in labels.iter().zip(self.buckets.iter()) {
+            let bar = "█".repeat((bucket.count * width).div_ceil(most));
+            if bar.is_empty() {
+                lines.push(format!("{:<label_width$} | {}", label, bucket.count));
+            } else {
+                lines.push(format!("{:<label_width$} | {} {}", label, bar, bucket.count));
+            }
+        }
+        return lines.join("\n");
+    }
+}
+
+impl Elves {
+    /// Returns every elf's total calories, in elf ID order.
+    fn get_totals(&self) -> Vec<i32> {
+        let mut totals: Vec<(ElfId, i32)> = self.elves.iter().map(|(elf_id, elf)| (*elf_id, elf.get_total_calories())).collect();
+        totals.sort();
+        return totals.into_iter().map(|(_, total)| total).collect();
+    }
+
+    /// Returns summary statistics of the elves' total calories, or `None` if there are no elves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::y2022::day_1::Elves;
+    /// let elves: Elves = Elves::from_text("1000\n2000\n\n4000\n\n5000\n6000\n")?;
+    ///
+    /// let statistics = elves.get_statistics().unwrap();
+    /// assert_eq!(statistics.max(), 11000);
+    /// assert_eq!(statistics.median(), 4000.0);
+    /// # Ok::<(), advent_of_code::Error>(())
+    /// ```
+    pub fn get_statistics(&self) -> Option<CalorieStatistics> {
+        return CalorieStatistics::new(self.get_totals());
+    }
+
+    /// Returns the number of food items each elf carries, in elf ID order.
+    pub fn get_food_counts(&self) -> Vec<(ElfId, usize)> {
+        let mut counts: Vec<(ElfId, usize)> = self.elves.iter().map(|(elf_id, elf)| (*elf_id, elf.get_total_foods())).collect();
+        counts.sort();
+        return counts;
+    }
+
+    /// Returns a histogram of the elves' total calories.
+    ///
+    /// # Arguments
+    ///
+    /// * `buckets` - the most buckets to use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::y2022::day_1::Elves;
+    /// let elves: Elves = Elves::from_text("1000\n\n1500\n\n2000\n\n3999\n")?;
+    ///
+    /// assert_eq!(elves.get_histogram(3).render(4), "1000-1999 | ████ 2\n2000-2999 | ██ 1\n3000-3999 | ██ 1");
+    /// # Ok::<(), advent_of_code::Error>(())
+    /// ```
+    pub fn get_histogram(&self, buckets: usize) -> Histogram {
+        return Histogram::new(&self.get_totals(), buckets);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statistics() {
+        let statistics = CalorieStatistics::new(vec![6000, 4000, 11000, 24000, 10000]).unwrap();
+        assert_eq!(5, statistics.count());
+        assert_eq!(4000, statistics.min());
+        assert_eq!(24000, statistics.max());
+        assert_eq!(11000.0, statistics.mean());
+        assert_eq!(10000.0, statistics.median());
+        assert_eq!(4000, statistics.percentile(0));
+        assert_eq!(4000, statistics.percentile(20));
+        assert_eq!(6000, statistics.percentile(21));
+        assert_eq!(24000, statistics.percentile(100));
+        assert!((statistics.standard_deviation() - 6985.69967).abs() < 1e-3, "{}", statistics.standard_deviation());
+    }
+
+    #[test]
+    fn test_statistics_even_count() {
+        let statistics = CalorieStatistics::new(vec![4, 1, 3, 2]).unwrap();
+        assert_eq!(2.5, statistics.median());
+        assert_eq!(None, CalorieStatistics::new(Vec::new()));
+    }
+
+    #[test]
+    fn test_elves_statistics() {
+        let elves = Elves::from_text("1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n").unwrap();
+        let statistics = elves.get_statistics().unwrap();
+        assert_eq!(5, statistics.count());
+        assert_eq!(11000.0, statistics.mean());
+        assert_eq!(None, Elves::new().get_statistics());
+
+        let counts: Vec<usize> = elves.get_food_counts().into_iter().map(|(_, count)| count).collect();
+        assert_eq!(vec![3, 1, 2, 3, 1], counts);
+        assert_eq!(0, elves.get_food_counts()[0].0.index());
+    }
+
+    #[test]
+    fn test_histogram() {
+        let histogram = Histogram::new(&[6000, 4000, 11000, 24000, 10000], 3);
+        assert_eq!(
+            vec![
+                Bucket { start: 4000, end: 10666, count: 3 },
+                Bucket { start: 10667, end: 17333, count: 1 },
+                Bucket { start: 17334, end: 24000, count: 1 },
+            ],
+            histogram.buckets()
+        );
+        assert_eq!(vec![Bucket { start: 5, end: 5, count: 2 }], Histogram::new(&[5, 5], 4).buckets());
+        assert_eq!(1, Histogram::new(&[1, 2, 3], 0).buckets().len());
+        assert!(Histogram::new(&[], 3).buckets().is_empty());
+    }
+
+    #[test]
+    fn test_histogram_bucket_limit() {
+        let histogram = Histogram::new(&[i32::MIN, 0, i32::MAX], usize::MAX);
+        assert_eq!(MAX_BUCKETS, histogram.buckets().len());
+        assert_eq!(3, histogram.buckets().iter().map(|bucket| bucket.count).sum::<usize>());
+        assert_eq!(i32::MAX, histogram.buckets()[MAX_BUCKETS - 1].end);
+    }
+
+    #[test]
+    fn test_render() {
+        let histogram = Histogram::new(&[1, 2, 2, 2, 2, 9, 10], 2);
+        assert_eq!("1-5  | ████████ 5\n6-10 | ████ 2", histogram.render(8));
+        assert_eq!("1-1 | █ 1\n2-2 | 0\n3-3 | ██ 2", Histogram::new(&[1, 3, 3], 3).render(2));
+        assert_eq!("", Histogram::new(&[], 3).render(8));
+    }
+}
@@ -1,12 +1,39 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
+use std::io::{self, BufRead};
+
+use crate::error::{Error, Result};
+use crate::input;
+use crate::solution::Solution;
+
+/// Returns the opponent's hand and the second column from a line of the strategy guide.
+///
+/// # Arguments
+///
+/// * `line` - a line of the strategy guide, such as `A Y`.
+fn parse_round(line: &str) -> Result<(&str, &str)> {
+    let split: Vec<&str> = line.split_ascii_whitespace().collect();
+    if split.len() != 2 {
+        return Err(Error::parse(line, "expected two columns separated by a space"));
+    }
+    if !matches!(split[0], "A" | "B" | "C") {
+        return Err(Error::parse(line, "expected the opponent's hand to be A, B or C"));
+    }
+    if !matches!(split[1], "X" | "Y" | "Z") {
+        return Err(Error::parse(line, "expected the second column to be X, Y or Z"));
+    }
+    return Ok((split[0], split[1]));
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct Score {
     current_score: u32,
 }
 
+impl Default for Score {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 impl Score {
     pub fn new() -> Self {
         Self { current_score: 0 }
@@ -50,6 +77,12 @@ pub struct Game {
     current_round: u32,
 }
 
+impl Default for Game {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 impl Game {
     pub fn new() -> Self {
         Self {
@@ -58,23 +91,28 @@ impl Game {
         }
     }
 
-    pub fn load_strategy_game(filepath: &str) -> Self {
-        let path = Path::new(filepath);
-        let mut instance = Self::new();
+    pub fn load_strategy_game(filepath: &str) -> Result<Self> {
+        return Self::from_reader(input::open(filepath)?);
+    }
 
-        let file = File::open(path.as_os_str()).expect("Unable to load file!");
-        let reader = BufReader::new(file);
+    pub fn from_text(text: &str) -> Result<Self> {
+        return Self::from_reader(text.as_bytes());
+    }
+
+    pub fn from_stdin() -> Result<Self> {
+        return Self::from_reader(io::stdin().lock());
+    }
 
-        for line in reader.lines() {
-            if let Ok(ip) = line {
-                let split: Vec<&str> = ip.split_ascii_whitespace().collect();
-                let opponent = split.first().unwrap();
-                let player = split.last().unwrap();
-                instance.add_round(opponent, player);
-            }
+    pub fn from_reader(reader: impl BufRead) -> Result<Self> {
+        let mut instance = Self::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let ip = line?;
+            let (opponent, player) = parse_round(&ip).map_err(|err| err.at_line(index + 1))?;
+            instance.add_round(opponent, player);
         }
 
-        return instance;
+        return Ok(instance);
     }
 
     pub fn add_round(&mut self, opponents_hand: &str, players_hand: &str) {
@@ -101,6 +139,12 @@ pub struct GameTwo {
     current_round: u32,
 }
 
+impl Default for GameTwo {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 impl GameTwo {
     pub fn new() -> Self {
         Self {
@@ -109,23 +153,28 @@ impl GameTwo {
         }
     }
 
-    pub fn load_strategy_game(filepath: &str) -> Self {
-        let path = Path::new(filepath);
-        let mut instance = Self::new();
+    pub fn load_strategy_game(filepath: &str) -> Result<Self> {
+        return Self::from_reader(input::open(filepath)?);
+    }
 
-        let file = File::open(path.as_os_str()).expect("Unable to load file!");
-        let reader = BufReader::new(file);
+    pub fn from_text(text: &str) -> Result<Self> {
+        return Self::from_reader(text.as_bytes());
+    }
+
+    pub fn from_stdin() -> Result<Self> {
+        return Self::from_reader(io::stdin().lock());
+    }
 
-        for line in reader.lines() {
-            if let Ok(ip) = line {
-                let split: Vec<&str> = ip.split_ascii_whitespace().collect();
-                let opponent = split.first().unwrap();
-                let player = split.last().unwrap();
-                instance.add_round(opponent, player);
-            }
+    pub fn from_reader(reader: impl BufRead) -> Result<Self> {
+        let mut instance = Self::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let ip = line?;
+            let (opponent, player) = parse_round(&ip).map_err(|err| err.at_line(index + 1))?;
+            instance.add_round(opponent, player);
         }
 
-        return instance;
+        return Ok(instance);
     }
 
     pub fn add_round(&mut self, opponents_hand: &str, round_end: &str) {
@@ -162,13 +211,37 @@ impl GameTwo {
     }
 }
 
+/// Both readings of the strategy guide, scored from the same input.
+#[derive(Debug)]
+pub struct StrategyGuide {
+    game: Game,
+    game_two: GameTwo,
+}
+
+impl Solution for StrategyGuide {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            game: Game::from_text(input)?,
+            game_two: GameTwo::from_text(input)?,
+        });
+    }
+
+    fn part_one(&self) -> String {
+        return self.game.get_current_score().to_string();
+    }
+
+    fn part_two(&self) -> String {
+        return self.game_two.get_current_score().to_string();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_empty_game() {
-        let mut game: Game = Game::new();
+        let game: Game = Game::new();
         assert_eq!(0, game.get_current_score());
     }
 
@@ -204,7 +277,7 @@ mod tests {
 
     #[test]
     fn test_empty_game_two() {
-        let mut game: GameTwo = GameTwo::new();
+        let game: GameTwo = GameTwo::new();
         assert_eq!(0, game.get_current_score());
     }
 
@@ -237,4 +310,47 @@ mod tests {
         game.add_round("C", "Z");
         assert_eq!(12, game.get_current_score());
     }
+
+    #[test]
+    fn test_parse_round() {
+        assert_eq!(("A", "Y"), parse_round("A Y").unwrap());
+    }
+
+    #[test]
+    fn test_parse_round_invalid_hand() {
+        let error = parse_round("D Y").unwrap_err();
+        assert_eq!("expected the opponent's hand to be A, B or C (found \"D Y\")", error.to_string());
+        assert!(parse_round("A W").is_err());
+    }
+
+    #[test]
+    fn test_parse_round_missing_column() {
+        assert!(parse_round("A").is_err());
+        assert!(parse_round("").is_err());
+    }
+
+    #[test]
+    fn test_from_text() {
+        let cases = [
+            ("A Y\nB X\nC Z\n", 15, 12),
+            ("A Y\nB X\nC Z", 15, 12),
+            ("A X\n", 4, 3),
+            ("", 0, 0),
+        ];
+        for (text, score, score_two) in cases {
+            assert_eq!(score, Game::from_text(text).unwrap().get_current_score(), "{:?}", text);
+            assert_eq!(score_two, GameTwo::from_text(text).unwrap().get_current_score(), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_from_text_invalid_round() {
+        let error = GameTwo::from_text("A Y\nB\n").unwrap_err();
+        assert_eq!("line 2: expected two columns separated by a space (found \"B\")", error.to_string());
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        assert!(matches!(Game::load_strategy_game("src/y2022/day_2/missing.txt"), Err(Error::Io { .. })));
+    }
 }
@@ -1,23 +1,45 @@
 use core::slice::Iter;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead};
+
+use crate::error::{Error, Result};
+use crate::input;
+use crate::solution::Solution;
+
+/// Checks that every item in a line of rucksack items is a letter.
+///
+/// # Arguments
+///
+/// * `line` - the rucksack items, one letter per item.
+fn check_items(line: &str) -> Result<()> {
+    if !line.chars().all(|item| item.is_ascii_alphabetic()) {
+        return Err(Error::parse(line, "expected rucksack items to be the letters a-z or A-Z"));
+    }
+    return Ok(());
+}
 
 #[derive(Debug)]
 pub struct Compartment {
     items: Vec<String>,
 }
 
+impl Default for Compartment {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 impl Compartment {
     pub fn new() -> Self {
         Self { items: Vec::new() }
     }
 
-    pub fn from_line(line: String) -> Self {
+    pub fn from_line(line: String) -> Result<Self> {
+        check_items(&line)?;
         let mut items: Vec<String> = Vec::new();
-        for item in line.chars().into_iter() {
+        for item in line.chars() {
             items.push(String::from(item))
         }
-        return Self { items: items };
+        return Ok(Self { items });
     }
 
     pub fn add_item(&mut self, item: &str) {
@@ -32,7 +54,7 @@ impl Compartment {
         return items_s;
     }
 
-    pub fn iter(&self) -> Iter<String> {
+    pub fn iter(&self) -> Iter<'_, String> {
         return self.items.iter();
     }
 
@@ -47,6 +69,12 @@ pub struct Rucksack {
     right_compartment: Compartment,
 }
 
+impl Default for Rucksack {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 impl Rucksack {
     pub fn new() -> Self {
         Self {
@@ -55,13 +83,17 @@ impl Rucksack {
         }
     }
 
-    pub fn from_text_line(line: &str) -> Self {
+    pub fn from_text_line(line: &str) -> Result<Self> {
+        check_items(line)?;
+        if !line.len().is_multiple_of(2) {
+            return Err(Error::parse(line, "expected an even number of items to split between the compartments"));
+        }
         let mut left: String = String::from(line);
         let right = left.split_off(left.len() / 2);
-        return Self {
-            left_compartment: Compartment::from_line(left),
-            right_compartment: Compartment::from_line(right),
-        };
+        return Ok(Self {
+            left_compartment: Compartment::from_line(left)?,
+            right_compartment: Compartment::from_line(right)?,
+        });
     }
 
     pub fn add_left_item(&mut self, item: &str) {
@@ -75,7 +107,7 @@ impl Rucksack {
     pub fn get_shared_items(&self) -> Vec<String> {
         let mut shared_items: Vec<String> = Vec::new();
         for left_item in self.left_compartment.iter() {
-            if !shared_items.contains(&left_item) && self.right_compartment.contains(&left_item) {
+            if !shared_items.contains(left_item) && self.right_compartment.contains(left_item) {
                 shared_items.push(left_item.clone());
             }
         }
@@ -95,6 +127,12 @@ pub struct RucksackPriorities {
     alphabet: [char; 26],
 }
 
+impl Default for RucksackPriorities {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 impl RucksackPriorities {
     pub fn new() -> Self {
         Self {
@@ -103,19 +141,27 @@ impl RucksackPriorities {
         }
     }
 
-    pub fn load_from_file(filepath: &str) -> Self {
-        let path = String::from(filepath);
+    pub fn load_from_file(filepath: &str) -> Result<Self> {
+        return Self::from_reader(input::open(filepath)?);
+    }
+
+    pub fn from_text(text: &str) -> Result<Self> {
+        return Self::from_reader(text.as_bytes());
+    }
+
+    pub fn from_stdin() -> Result<Self> {
+        return Self::from_reader(io::stdin().lock());
+    }
+
+    pub fn from_reader(reader: impl BufRead) -> Result<Self> {
         let mut instance = Self::new();
-        let file = File::open(path).expect("Unable to load file!");
-        let reader = BufReader::new(file);
 
-        for line in reader.lines() {
-            if let Ok(ip) = line {
-                let rucksack = Rucksack::from_text_line(&ip);
-                instance.add_rucksack_priority(rucksack);
-            }
+        for (index, line) in reader.lines().enumerate() {
+            let ip = line?;
+            let rucksack = Rucksack::from_text_line(&ip).map_err(|err| err.at_line(index + 1))?;
+            instance.add_rucksack_priority(rucksack);
         }
-        return instance;
+        return Ok(instance);
     }
 
     pub fn add_rucksack_priority(&mut self, rucksack: Rucksack) {
@@ -160,6 +206,12 @@ pub struct ElfGroup {
   rucksacks: Vec<String>
 }
 
+impl Default for ElfGroup {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 impl ElfGroup {
   pub fn new() -> Self {
     Self { rucksacks: Vec::new() }
@@ -169,18 +221,18 @@ impl ElfGroup {
     self.rucksacks.push(String::from(rucksack));
   }
 
-  pub fn find_badge(&mut self) -> String {
+  pub fn find_badge(&self) -> String {
     let mut common_items: Vec<char> = Vec::new();
-    let first_rucksack = self.rucksacks.remove(0);
-    let second_rucksack = self.rucksacks.remove(0);
+    let first_rucksack = &self.rucksacks[0];
+    let second_rucksack = &self.rucksacks[1];
     for item in first_rucksack.chars() {
-      if !common_items.contains(&item) && second_rucksack.contains(&String::from(item)) {
+      if !common_items.contains(&item) && second_rucksack.contains(item) {
         common_items.push(item)
       }
     }
-    let third_rucksack = self.rucksacks.remove(0);
+    let third_rucksack = &self.rucksacks[2];
     for item in common_items.iter() {
-      if third_rucksack.contains(&String::from(*item)) {
+      if third_rucksack.contains(*item) {
         return String::from(*item);
       }
     }
@@ -194,6 +246,12 @@ pub struct ElfGroups {
   current_group_count: u8,
 }
 
+impl Default for ElfGroups {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 impl ElfGroups {
     pub fn new() -> Self {
         Self {
@@ -202,19 +260,46 @@ impl ElfGroups {
         }
     }
 
-  pub fn load_from_file(filepath: &str) -> Self {
-        let path = String::from(filepath);
+  pub fn load_from_file(filepath: &str) -> Result<Self> {
+        return Self::from_reader(input::open(filepath)?);
+    }
+
+  pub fn from_text(text: &str) -> Result<Self> {
+        return Self::from_reader(text.as_bytes());
+    }
+
+  pub fn from_stdin() -> Result<Self> {
+        return Self::from_reader(io::stdin().lock());
+    }
+
+  pub fn from_reader(reader: impl BufRead) -> Result<Self> {
         let mut instance = Self::new();
-        let file = File::open(path).expect("Unable to load file!");
-        let reader = BufReader::new(file);
 
-        for line in reader.lines() {
-            if let Ok(ip) = line {
-                instance.add_rucksack(&ip);
-            }
+        for (index, line) in reader.lines().enumerate() {
+            let ip = line?;
+            check_items(&ip).map_err(|err| err.at_line(index + 1))?;
+            instance.add_rucksack(&ip);
         }
-        return instance;
+        instance.validate()?;
+        return Ok(instance);
+    }
+
+  /// Checks that every group has three rucksacks with a badge item in common.
+  fn validate(&self) -> Result<()> {
+    for (index, group) in self.elf_badge_groups.iter().enumerate() {
+      if group.rucksacks.len() != 3 {
+        return Err(Error::Validation(format!(
+          "elf group {} has {} rucksacks, expected 3",
+          index + 1,
+          group.rucksacks.len()
+        )));
+      }
+      if group.find_badge().is_empty() {
+        return Err(Error::Validation(format!("elf group {} has no badge item in common", index + 1)));
+      }
     }
+    return Ok(());
+  }
 
   pub fn add_rucksack(&mut self, rucksack: &str) {
     self.check_create_new_group();
@@ -226,7 +311,7 @@ impl ElfGroups {
   }
 
   fn check_create_new_group(&mut self) {
-    if self.current_group_count >= 3 || self.elf_badge_groups.len() == 0 {
+    if self.current_group_count >= 3 || self.elf_badge_groups.is_empty() {
       self.current_group_count = 0;
       self.create_new_group();
     }
@@ -240,9 +325,9 @@ impl ElfGroups {
     self.current_group_count += 1;
   }
 
-  pub fn get_priority_points(&mut self) -> u32 {
+  pub fn get_priority_points(&self) -> u32 {
     let mut points: u32 = 0;
-    for group in self.elf_badge_groups.iter_mut() {
+    for group in self.elf_badge_groups.iter() {
       let mut priority = RucksackPriorities::new();
       let shared_item = group.find_badge();
       priority.add_shared_item_priority(&shared_item);
@@ -252,13 +337,37 @@ impl ElfGroups {
   }
 }
 
+/// The rucksack priorities and the elf group badges, loaded from the same list of rucksacks.
+#[derive(Debug)]
+pub struct Rucksacks {
+    priorities: RucksackPriorities,
+    groups: ElfGroups,
+}
+
+impl Solution for Rucksacks {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            priorities: RucksackPriorities::from_text(input)?,
+            groups: ElfGroups::from_text(input)?,
+        });
+    }
+
+    fn part_one(&self) -> String {
+        return self.priorities.get_priority_points().to_string();
+    }
+
+    fn part_two(&self) -> String {
+        return self.groups.get_priority_points().to_string();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_first_rucksack() {
-        let rs = Rucksack::from_text_line("vJrwpWtwJgWrhcsFMMfFFhFp");
+        let rs = Rucksack::from_text_line("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
         assert_eq!("vJrwpWtwJgWr", rs.left_compartment.items_as_string());
         assert_eq!("hcsFMMfFFhFp", rs.right_compartment.items_as_string());
         assert_eq!(vec!["p"], rs.get_shared_items());
@@ -266,7 +375,7 @@ mod tests {
 
     #[test]
     fn test_second_rucksack() {
-        let rs = Rucksack::from_text_line("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL");
+        let rs = Rucksack::from_text_line("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL").unwrap();
         assert_eq!("jqHRNqRjqzjGDLGL", rs.left_compartment.items_as_string());
         assert_eq!("rsFMfFZSrLrFZsSL", rs.right_compartment.items_as_string());
         assert_eq!(vec!["L"], rs.get_shared_items());
@@ -274,7 +383,7 @@ mod tests {
 
     #[test]
     fn test_third_rucksack() {
-        let rs = Rucksack::from_text_line("PmmdzqPrVvPwwTWBwg");
+        let rs = Rucksack::from_text_line("PmmdzqPrVvPwwTWBwg").unwrap();
         assert_eq!("PmmdzqPrV", rs.left_compartment.items_as_string());
         assert_eq!("vPwwTWBwg", rs.right_compartment.items_as_string());
         assert_eq!(vec!["P"], rs.get_shared_items());
@@ -282,31 +391,76 @@ mod tests {
 
     #[test]
     fn test_fourth_rucksack() {
-        let rs = Rucksack::from_text_line("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn");
+        let rs = Rucksack::from_text_line("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn").unwrap();
         assert_eq!(vec!["v"], rs.get_shared_items());
     }
 
     #[test]
     fn test_fifth_rucksack() {
-        let rs = Rucksack::from_text_line("ttgJtRGJQctTZtZT");
+        let rs = Rucksack::from_text_line("ttgJtRGJQctTZtZT").unwrap();
         assert_eq!(vec!["t"], rs.get_shared_items());
     }
 
     #[test]
     fn test_sixth_rucksack() {
-        let rs = Rucksack::from_text_line("CrZsJsPPZsGzwwsLwLmpwMDw");
+        let rs = Rucksack::from_text_line("CrZsJsPPZsGzwwsLwLmpwMDw").unwrap();
         assert_eq!(vec!["s"], rs.get_shared_items());
     }
 
     #[test]
     fn test_rucksack_priority() {
         let mut rsp = RucksackPriorities::new();
-        rsp.add_rucksack_priority(Rucksack::from_text_line("vJrwpWtwJgWrhcsFMMfFFhFp"));
-        rsp.add_rucksack_priority(Rucksack::from_text_line("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL"));
-        rsp.add_rucksack_priority(Rucksack::from_text_line("PmmdzqPrVvPwwTWBwg"));
-        rsp.add_rucksack_priority(Rucksack::from_text_line("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn"));
-        rsp.add_rucksack_priority(Rucksack::from_text_line("ttgJtRGJQctTZtZT"));
-        rsp.add_rucksack_priority(Rucksack::from_text_line("CrZsJsPPZsGzwwsLwLmpwMDw"));
+        rsp.add_rucksack_priority(Rucksack::from_text_line("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap());
+        rsp.add_rucksack_priority(Rucksack::from_text_line("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL").unwrap());
+        rsp.add_rucksack_priority(Rucksack::from_text_line("PmmdzqPrVvPwwTWBwg").unwrap());
+        rsp.add_rucksack_priority(Rucksack::from_text_line("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn").unwrap());
+        rsp.add_rucksack_priority(Rucksack::from_text_line("ttgJtRGJQctTZtZT").unwrap());
+        rsp.add_rucksack_priority(Rucksack::from_text_line("CrZsJsPPZsGzwwsLwLmpwMDw").unwrap());
         assert_eq!(157, rsp.get_priority_points());
     }
+
+    #[test]
+    fn test_from_text() {
+        let example = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\n\
+            wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw\n";
+        let cases = [(example, 157, 70), ("aBcaDe\nfGhiaf\najklma\n", 8, 1)];
+        for (text, priority_points, badge_points) in cases {
+            assert_eq!(priority_points, RucksackPriorities::from_text(text).unwrap().get_priority_points());
+            assert_eq!(badge_points, ElfGroups::from_text(text).unwrap().get_priority_points());
+        }
+    }
+
+    #[test]
+    fn test_elf_groups_from_text_invalid_item() {
+        let error = ElfGroups::from_text("abc\nab c\nabc\n").unwrap_err();
+        assert_eq!("line 2: expected rucksack items to be the letters a-z or A-Z (found \"ab c\")", error.to_string());
+    }
+
+    #[test]
+    fn test_rucksack_odd_number_of_items() {
+        assert!(matches!(Rucksack::from_text_line("abc"), Err(Error::Parse { .. })));
+    }
+
+    #[test]
+    fn test_rucksack_invalid_item() {
+        let error = Rucksack::from_text_line("ab1d").unwrap_err();
+        assert_eq!("expected rucksack items to be the letters a-z or A-Z (found \"ab1d\")", error.to_string());
+    }
+
+    #[test]
+    fn test_elf_groups_incomplete_group() {
+        let mut eg = ElfGroups::new();
+        eg.add_rucksack("vJrwpWtwJgWrhcsFMMfFFhFp");
+        eg.add_rucksack("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL");
+        assert!(matches!(eg.validate(), Err(Error::Validation(_))));
+    }
+
+    #[test]
+    fn test_elf_groups_without_badge() {
+        let mut eg = ElfGroups::new();
+        eg.add_rucksack("ab");
+        eg.add_rucksack("cd");
+        eg.add_rucksack("ef");
+        assert!(matches!(eg.validate(), Err(Error::Validation(_))));
+    }
 }
@@ -1,6 +1,10 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead};
 
+use crate::error::{Error, Result};
+use crate::input;
+use crate::solution::Solution;
+
+#[derive(Debug)]
 pub struct SectionAssignment {
     start_section_id: u8,
     end_section_id: u8,
@@ -9,16 +13,25 @@ pub struct SectionAssignment {
 impl SectionAssignment {
     pub fn new(start_section_id: u8, end_section_id: u8) -> Self {
         Self {
-            start_section_id: start_section_id,
-            end_section_id: end_section_id,
+            start_section_id,
+            end_section_id,
         }
     }
 
-    pub fn from_line(line: &str) -> Self {
-        let mut split = line.split("-");
-        let first: u8 = split.next().unwrap().parse().unwrap();
-        let second: u8 = split.next().unwrap().parse().unwrap();
-        return Self::new(first, second);
+    pub fn from_line(line: &str) -> Result<Self> {
+        let Some((first, second)) = line.split_once('-') else {
+            return Err(Error::parse(line, "expected a range of section IDs such as 2-4"));
+        };
+        let first: u8 = first
+            .parse()
+            .map_err(|_| Error::parse(line, "expected section IDs to be numbers from 0 to 255"))?;
+        let second: u8 = second
+            .parse()
+            .map_err(|_| Error::parse(line, "expected section IDs to be numbers from 0 to 255"))?;
+        if first > second {
+            return Err(Error::parse(line, "expected the range to start before it ends"));
+        }
+        return Ok(Self::new(first, second));
     }
 
     pub fn get_start_id(&self) -> u8 {
@@ -30,18 +43,21 @@ impl SectionAssignment {
     }
 }
 
+#[derive(Debug)]
 pub struct Section {
     first_elf: SectionAssignment,
     second_elf: SectionAssignment,
 }
 
 impl Section {
-    pub fn from_line(text_line: &str) -> Self {
-        let mut split = text_line.split(",");
-        return Self {
-            first_elf: SectionAssignment::from_line(split.next().unwrap()),
-            second_elf: SectionAssignment::from_line(split.next().unwrap())
-        }
+    pub fn from_line(text_line: &str) -> Result<Self> {
+        let Some((first, second)) = text_line.split_once(',') else {
+            return Err(Error::parse(text_line, "expected two section ranges separated by a comma"));
+        };
+        return Ok(Self {
+            first_elf: SectionAssignment::from_line(first)?,
+            second_elf: SectionAssignment::from_line(second)?,
+        });
     }
 
     pub fn get_first_elf(&self) -> &SectionAssignment {
@@ -72,13 +88,8 @@ impl Section {
         if self.get_first_start_id() < self.get_second_start_id() {
             return self.get_first_end_id() >= self.get_second_end_id();
         } else if self.get_first_start_id() == self.get_second_start_id() {
-            if self.get_first_end_id() >= self.get_first_start_id() && self.get_second_end_id() >= self.get_second_start_id() {
-                return true;
-            } else if self.get_first_end_id() <= self.get_first_start_id() && self.get_second_end_id() <= self.get_second_start_id() {
-                return true;
-            } else {
-                return false;
-            }
+            return (self.get_first_end_id() >= self.get_first_start_id() && self.get_second_end_id() >= self.get_second_start_id())
+                || (self.get_first_end_id() <= self.get_first_start_id() && self.get_second_end_id() <= self.get_second_start_id());
         } else {
             return self.get_second_end_id() >= self.get_first_end_id();
         }
@@ -101,29 +112,43 @@ pub struct Sections {
     partially_contains_count: u32,
 }
 
+impl Default for Sections {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 impl Sections {
     pub fn new() -> Self {
         Self { fully_contains_count: 0, partially_contains_count: 0 }
     }
 
-    pub fn from_file(filepath: &str) -> Self {
-        let path = String::from(filepath);
+    pub fn from_file(filepath: &str) -> Result<Self> {
+        return Self::from_reader(input::open(filepath)?);
+    }
+
+    pub fn from_text(text: &str) -> Result<Self> {
+        return Self::from_reader(text.as_bytes());
+    }
+
+    pub fn from_stdin() -> Result<Self> {
+        return Self::from_reader(io::stdin().lock());
+    }
+
+    pub fn from_reader(reader: impl BufRead) -> Result<Self> {
         let mut instance = Self::new();
-        let file = File::open(path).expect("Unable to load file!");
-        let reader = BufReader::new(file);
-
-        for line in reader.lines() {
-            if let Ok(ip) = line {
-                let section = Section::from_line(&ip);
-                if section.fully_contains() {
-                    instance.fully_contains_count += 1;
-                }
-                if section.partial_contains() {
-                    instance.partially_contains_count += 1;
-                }
+
+        for (index, line) in reader.lines().enumerate() {
+            let ip = line?;
+            let section = Section::from_line(&ip).map_err(|err| err.at_line(index + 1))?;
+            if section.fully_contains() {
+                instance.fully_contains_count += 1;
+            }
+            if section.partial_contains() {
+                instance.partially_contains_count += 1;
             }
         }
-        return instance;
+        return Ok(instance);
     }
 
     pub fn get_fully_count(&self) -> u32 {
@@ -135,6 +160,20 @@ impl Sections {
     }
 }
 
+impl Solution for Sections {
+    fn parse(input: &str) -> Result<Self> {
+        return Self::from_text(input);
+    }
+
+    fn part_one(&self) -> String {
+        return self.get_fully_count().to_string();
+    }
+
+    fn part_two(&self) -> String {
+        return self.get_partially_count().to_string();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,7 +181,7 @@ mod tests {
     #[test]
     fn test_create_section_assignment_from_line() {
         let line = "2-8";
-        let sa = SectionAssignment::from_line(&line);
+        let sa = SectionAssignment::from_line(line).unwrap();
         assert_eq!(2, sa.get_start_id());
         assert_eq!(8, sa.get_end_id());
     }
@@ -150,7 +189,7 @@ mod tests {
     #[test]
     fn test_create_section_from_line() {
         let line = "60-60,45-60";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(60, section.get_first_elf().get_start_id());
         assert_eq!(60, section.get_first_elf().get_end_id());
         assert_eq!(45, section.get_second_elf().get_start_id());
@@ -161,148 +200,187 @@ mod tests {
     #[test]
     fn test_fully_contains_1() {
         let line = "2-4,6-8";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(false, section.fully_contains());
     }
 
     #[test]
     fn test_fully_contains_2() {
         let line = "2-3,4-5";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(false, section.fully_contains());
     }
 
     #[test]
     fn test_fully_contains_3() {
         let line = "5-7,7-9";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(false, section.fully_contains());
     }
 
     #[test]
     fn test_fully_contains_4() {
         let line = "2-8,3-7";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.fully_contains());
     }
 
     #[test]
     fn test_fully_contains_5() {
         let line = "6-6,4-6";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.fully_contains());
     }
 
     #[test]
     fn test_fully_contains_6() {
         let line = "2-6,4-8";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(false, section.fully_contains());
     }
 
     #[test]
     fn test_fully_contains_7() {
         let line = "5-7,2-8";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.fully_contains());
     }
 
     #[test]
     fn test_fully_contains_8() {
         let line = "4-90,4-4";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.fully_contains());
     }
 
     #[test]
     fn test_fully_contains_9() {
         let line = "4-4,4-90";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.fully_contains());
     }
 
     #[test]
     fn test_fully_contains_10() {
         let line = "4-90,6-90";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.fully_contains());
     }
 
     #[test]
     fn test_partial_contains_1() {
         let line = "2-4,6-8";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(false, section.partial_contains());
     }
 
     #[test]
     fn test_partial_contains_2() {
         let line = "2-3,4-5";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(false, section.partial_contains());
     }
 
     #[test]
     fn test_partial_contains_3() {
         let line = "5-7,7-9";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.partial_contains());
     }
 
     #[test]
     fn test_partial_contains_4() {
         let line = "2-8,3-7";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.partial_contains());
     }
 
     #[test]
     fn test_partial_contains_5() {
         let line = "6-6,4-6";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.partial_contains());
     }
 
     #[test]
     fn test_partial_contains_6() {
         let line = "2-6,4-8";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.partial_contains());
     }
 
     #[test]
     fn test_partial_contains_7() {
         let line = "5-7,2-8";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.partial_contains());
     }
 
     #[test]
     fn test_partial_contains_8() {
         let line = "4-90,4-4";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.partial_contains());
     }
 
     #[test]
     fn test_partial_contains_9() {
         let line = "4-4,4-90";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.partial_contains());
     }
 
     #[test]
     fn test_partial_contains_10() {
         let line = "4-90,6-90";
-        let section = Section::from_line(line);
+        let section = Section::from_line(line).unwrap();
         assert_eq!(true, section.partial_contains());
     }
 
 
     #[test]
     fn test_sections() {
-        let sections = Sections::from_file("src/day_4/test.txt");
+        let sections = Sections::from_file("src/y2022/day_4/examples/example.txt").unwrap();
         assert_eq!(2, sections.get_fully_count());
         assert_eq!(4, sections.get_partially_count());
     }
+
+    #[test]
+    fn test_sections_from_text() {
+        let cases = [
+            ("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n", 2, 4),
+            ("4-90,4-4\n4-4,4-90", 2, 2),
+            ("1-1,2-2\n", 0, 0),
+            ("", 0, 0),
+        ];
+        for (text, fully, partially) in cases {
+            let sections = Sections::from_text(text).unwrap();
+            assert_eq!(fully, sections.get_fully_count(), "{:?}", text);
+            assert_eq!(partially, sections.get_partially_count(), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_sections_from_text_invalid_line() {
+        let error = Sections::from_text("2-4,6-8\n2-4;6-8\n").unwrap_err();
+        assert_eq!("line 2: expected two section ranges separated by a comma (found \"2-4;6-8\")", error.to_string());
+    }
+
+    #[test]
+    fn test_section_assignment_not_a_range() {
+        assert!(matches!(SectionAssignment::from_line("2"), Err(Error::Parse { .. })));
+        assert!(SectionAssignment::from_line("a-4").is_err());
+        assert!(SectionAssignment::from_line("2-256").is_err());
+    }
+
+    #[test]
+    fn test_section_assignment_backwards_range() {
+        let error = SectionAssignment::from_line("8-2").unwrap_err();
+        assert_eq!("expected the range to start before it ends (found \"8-2\")", error.to_string());
+    }
+
+    #[test]
+    fn test_section_missing_second_elf() {
+        assert!(Section::from_line("2-4").is_err());
+    }
 }
@@ -0,0 +1,6 @@
+//! Solutions to the puzzles of Advent of Code 2022.
+
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
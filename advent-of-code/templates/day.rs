@@ -0,0 +1,74 @@
+use std::io::{self, BufRead};
+
+use crate::error::Result;
+use crate::input;
+use crate::solution::{Solution, UNSOLVED};
+
+#[derive(Debug)]
+pub struct {{TYPE}} {
+    lines: Vec<String>,
+}
+
+impl Default for {{TYPE}} {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl {{TYPE}} {
+    pub fn new() -> Self {
+        Self { lines: Vec::new() }
+    }
+
+    pub fn load_from_file(filepath: &str) -> Result<Self> {
+        return Self::from_reader(input::open(filepath)?);
+    }
+
+    pub fn from_text(text: &str) -> Result<Self> {
+        return Self::from_reader(text.as_bytes());
+    }
+
+    pub fn from_stdin() -> Result<Self> {
+        return Self::from_reader(io::stdin().lock());
+    }
+
+    pub fn from_reader(reader: impl BufRead) -> Result<Self> {
+        let mut instance = Self::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let ip = line?;
+            instance.add_line(&ip).map_err(|err| err.at_line(index + 1))?;
+        }
+        return Ok(instance);
+    }
+
+    pub fn add_line(&mut self, line: &str) -> Result<()> {
+        self.lines.push(String::from(line));
+        return Ok(());
+    }
+}
+
+impl Solution for {{TYPE}} {
+    fn parse(input: &str) -> Result<Self> {
+        return Self::from_text(input);
+    }
+
+    fn part_one(&self) -> String {
+        return String::from(UNSOLVED);
+    }
+
+    fn part_two(&self) -> String {
+        return String::from(UNSOLVED);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_text() {
+        let puzzle = {{TYPE}}::from_text("first\nsecond\n").unwrap();
+        assert_eq!(2, puzzle.lines.len());
+    }
+}
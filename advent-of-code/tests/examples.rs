@@ -0,0 +1,20 @@
+//! Runs every puzzle example under `src/yYYYY/day_N/examples` against its expected answers.
+//!
+//! The tests themselves are generated by `build.rs`, one per example and part, so adding an
+//! example only needs the input and its `.toml` sidecar.
+
+#![allow(clippy::needless_return)]
+
+use std::fs;
+
+use advent_of_code::find_day;
+use advent_of_code::runner::solve;
+
+fn check_example(year: u16, day: u8, path: &str, part: u8, expected: &str) {
+    let day = find_day(year, day).unwrap_or_else(|| panic!("{} belongs to a day that is not registered", path));
+    let text = fs::read_to_string(path).unwrap();
+    let solution = day.parse(&text).unwrap_or_else(|err| panic!("{}: {}", path, err));
+    assert_eq!(expected, solve(solution.as_ref(), part), "{} part {}", path, part);
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
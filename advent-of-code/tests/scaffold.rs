@@ -0,0 +1,77 @@
+//! Scaffolds a new day in a copy of the crate, then checks that the copy still builds, passes its
+//! tests and verifies, with the new day skipped until it has an input. The copy reads its inputs
+//! from an empty directory, so the developer's cached inputs play no part.
+//!
+//! The copy and its build live under Cargo's temporary directory for integration tests, so the
+//! dependencies are only compiled on the first run.
+
+#![allow(clippy::needless_return)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// The files and directories copied into the scratch crate.
+const FILES: &[&str] = &["Cargo.toml", "Cargo.lock", "build.rs", "answers.toml", "aoc.toml", "src", "templates", "benches"];
+
+fn copy(from: &Path, to: &Path) {
+    if from.is_dir() {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let entry = entry.unwrap();
+            copy(&entry.path(), &to.join(entry.file_name()));
+        }
+    } else if from.is_file() {
+        fs::create_dir_all(to.parent().unwrap()).unwrap();
+        fs::copy(from, to).unwrap();
+    }
+}
+
+fn cargo(root: &Path, target: &Path, inputs: &Path, args: &[&str]) -> Output {
+    let mut command = Command::new(env!("CARGO"));
+    command.args(args).current_dir(root).env("CARGO_TARGET_DIR", target);
+    // The copy must not download inputs or pick up the developer's configuration.
+    for (key, _) in std::env::vars().filter(|(key, _)| key.starts_with("AOC_")) {
+        command.env_remove(key);
+    }
+    command.env("AOC_INPUTS", inputs);
+    let output = command.output().unwrap();
+    assert!(
+        output.status.success(),
+        "cargo {} failed:\n{}\n{}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    return output;
+}
+
+#[test]
+fn test_scaffolded_day_builds_tests_and_verifies() {
+    let scratch = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    let root = scratch.join("scaffold-crate");
+    let target = scratch.join("scaffold-target");
+    let inputs = scratch.join("scaffold-inputs");
+    let _ = fs::remove_dir_all(&root);
+    let _ = fs::remove_dir_all(&inputs);
+    fs::create_dir_all(&inputs).unwrap();
+    for file in FILES {
+        copy(&Path::new(env!("CARGO_MANIFEST_DIR")).join(file), &root.join(file));
+    }
+    copy(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/examples.rs"), &root.join("tests/examples.rs"));
+
+    cargo(&root, &target, &inputs, &["run", "--offline", "--quiet", "--", "new-day", "5", "--title", "Supply Stacks"]);
+    assert!(root.join("src/y2022/day_5/mod.rs").is_file());
+    assert_eq!("", fs::read_to_string(inputs.join("2022/5.txt")).unwrap());
+
+    cargo(&root, &target, &inputs, &["test", "--offline", "--quiet"]);
+    let verify = cargo(&root, &target, &inputs, &["run", "--offline", "--quiet", "--", "verify"]);
+    let stdout = String::from_utf8_lossy(&verify.stdout);
+    assert!(stdout.contains("2022 Day  5 Part 1: no input"), "{}", stdout);
+    let run = cargo(&root, &target, &inputs, &["run", "--offline", "--quiet", "--", "run", "--all", "--no-cache"]);
+    let stdout = String::from_utf8_lossy(&run.stdout);
+    assert!(stdout.contains("2022 Day 5: Supply Stacks (no input)"), "{}", stdout);
+    assert!(stdout.contains("2022 Day 1: Calorie Counting (no input)"), "{}", stdout);
+    fs::remove_dir_all(&root).unwrap();
+    fs::remove_dir_all(&inputs).unwrap();
+}